@@ -0,0 +1,49 @@
+//! Generates one regression test per day, input file and part from the
+//! `DayN/input*.txt` files, checked against the answers registry.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut tests = String::new();
+    // The whole crate rather than each `DayN`, so a day added by `aoc new`
+    // gets its tests on the next build
+    println!("cargo::rerun-if-changed=.");
+
+    let mut days: Vec<(u32, String)> = Vec::new();
+    for entry in fs::read_dir(root).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        if let Some(day) = name.strip_prefix("Day").and_then(|n| n.parse().ok()) {
+            days.push((day, name));
+        }
+    }
+    days.sort();
+
+    for (day, dir) in days {
+        let mut inputs: Vec<String> = fs::read_dir(root.join(&dir))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.starts_with("input") && name.ends_with(".txt"))
+            // A freshly scaffolded day has empty inputs until they are filled in
+            .filter(|name| fs::metadata(root.join(&dir).join(name)).is_ok_and(|m| m.len() > 0))
+            .collect();
+        inputs.sort();
+
+        for input in inputs {
+            let stem = input.trim_end_matches(".txt");
+            for (part, variant) in [(1, "One"), (2, "Two")] {
+                writeln!(
+                    tests,
+                    "#[test]\nfn day{day:02}_{stem}_part{part}() {{ check({day}, {input:?}, Part::{variant}); }}"
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(out, tests).unwrap();
+}
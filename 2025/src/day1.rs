@@ -0,0 +1,622 @@
+use aoc_core::parse::{self, Line};
+use aoc_core::trace::Field;
+use aoc_core::{AocError, InputRng, Param, Params, Solution, Trace};
+use rand::Rng;
+use std::cmp::Reverse;
+use tracing::debug;
+
+pub struct Day1;
+
+/// One line of the input: a rotation of the unnamed dial, like `L68`, or
+/// of a named one, like `A:R12`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    pub dial: Option<String>,
+    /// Clicks, to the right when positive
+    pub clicks: i64,
+}
+
+fn convert_string(line: Line) -> Result<Rotation, AocError> {
+    let (dial, value) = match line.text.split_once(':') {
+        Some(("", _)) => return Err(line.error_line("expected a dial name before `:`")),
+        Some((name, value)) => (Some(name.to_string()), value),
+        None => (None, line.text),
+    };
+    let Some(direction) = value.get(0..1) else {
+        return Err(line.error_line("expected a rotation like `L68` or `A:R12`"));
+    };
+    // Only digits: the direction gives the sign, and `-number` cannot overflow
+    let count = &value[1..];
+    if !count.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(line.error(count, "expected a count of clicks after the direction"));
+    }
+    let number: i64 = line.number(count)?;
+
+    let clicks = match direction {
+        "R" => number,
+        "L" => -number,
+        _ => return Err(line.error(direction, "expected direction `L` or `R`")),
+    };
+    Ok(Rotation { dial, clicks })
+}
+
+/// A dial numbered from 0 to `size - 1`, pointing at one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: i32,
+    position: i32,
+}
+
+/// What one rotation of a dial did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    /// Where the dial points afterwards
+    pub position: i32,
+    /// Clicks during the rotation that left the dial at 0, the last included
+    pub zeros: i64,
+}
+
+impl Turn {
+    /// Whether the rotation ended at 0.
+    pub fn landed(&self) -> bool {
+        self.position == 0
+    }
+}
+
+/// Zeros over a run of rotations.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Zeros {
+    /// Rotations that ended at 0
+    pub landings: i64,
+    /// Clicks that left the dial at 0, landings included
+    pub crossings: i64,
+}
+
+impl Dial {
+    /// A dial of `size` positions pointing at `start`.
+    pub fn new(size: i32, start: i32) -> Result<Self, AocError> {
+        if size < 1 {
+            return Err(AocError::new(format!(
+                "dial size {size} must be at least 1"
+            )));
+        }
+        if !(0..size).contains(&start) {
+            return Err(AocError::new(format!(
+                "start {start} is not on a dial of size {size}"
+            )));
+        }
+        Ok(Dial { size, position: start })
+    }
+
+    /// The dial the day's `size` and `start` parameters describe.
+    pub fn from_params(params: &Params) -> Result<Self, AocError> {
+        Dial::new(params.get("size")?, params.get("start")?)
+    }
+
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    /// Turn the dial by `clicks`, to the right when positive.
+    pub fn rotate(&mut self, clicks: i64) -> Turn {
+        let size = i64::from(self.size);
+        let position = i64::from(self.position);
+        // Clicks already taken towards the next 0 in the direction of travel:
+        // a 0 is passed for every multiple of `size` reached from there.
+        // Whole turns are split off first so that no sum can overflow.
+        let travelled = if clicks >= 0 { position } else { (size - position) % size };
+        let (turns, rest) = (clicks.abs() / size, clicks.abs() % size);
+        let zeros = turns + (travelled + rest) / size;
+        self.position = (position + clicks % size).rem_euclid(size) as i32;
+        Turn {
+            position: self.position,
+            zeros,
+        }
+    }
+
+    /// [`Dial::rotate`] one click at a time: slow, but plainly right, so the
+    /// arithmetic can be checked against it.
+    pub fn rotate_by_clicks(&mut self, clicks: i64) -> Turn {
+        let step = clicks.signum() as i32;
+        let mut zeros = 0;
+        for _ in 0..clicks.unsigned_abs() {
+            self.position = (self.position + step).rem_euclid(self.size);
+            if self.position == 0 {
+                zeros += 1;
+            }
+        }
+        Turn {
+            position: self.position,
+            zeros,
+        }
+    }
+}
+
+/// One dial of a [`Lock`], with the zeros it has seen.
+#[derive(Debug, Clone)]
+struct Wheel {
+    name: Option<String>,
+    dial: Dial,
+    zeros: Zeros,
+}
+
+/// A combination lock of dials turned independently, by name. The `dials`
+/// parameter declares dials like `A:10,B:60:3`, for a dial `A` of 10
+/// positions starting at 0 and a dial `B` of 60 starting at 3. The
+/// unnamed dial, and any name not declared, take `size` and `start`.
+#[derive(Debug, Clone)]
+pub struct Lock {
+    default: Dial,
+    wheels: Vec<Wheel>,
+}
+
+impl Lock {
+    pub fn from_params(params: &Params) -> Result<Self, AocError> {
+        let mut lock = Lock {
+            default: Dial::from_params(params)?,
+            wheels: Vec::new(),
+        };
+        let declared: String = params.get("dials")?;
+        for spec in declared.split(',').filter(|spec| !spec.is_empty()) {
+            let (name, dial) = declare(spec)?;
+            if lock.wheels.iter().any(|w| w.name.as_deref() == Some(name)) {
+                return Err(AocError::new(format!("dial `{name}` is declared twice")));
+            }
+            lock.wheels.push(Wheel {
+                name: Some(name.to_string()),
+                dial,
+                zeros: Zeros::default(),
+            });
+        }
+        Ok(lock)
+    }
+
+    /// The dial called `name` as it is now.
+    pub fn dial(&self, name: Option<&str>) -> Dial {
+        let wheel = self.wheels.iter().find(|w| w.name.as_deref() == name);
+        wheel.map_or(self.default, |w| w.dial)
+    }
+
+    /// Turn the dial the rotation names, counting its zeros.
+    pub fn rotate(&mut self, rotation: &Rotation) -> Turn {
+        self.rotate_with(rotation, Dial::rotate)
+    }
+
+    /// [`Lock::rotate`] with the dial turned one click at a time.
+    pub fn rotate_by_clicks(&mut self, rotation: &Rotation) -> Turn {
+        self.rotate_with(rotation, Dial::rotate_by_clicks)
+    }
+
+    fn rotate_with(&mut self, rotation: &Rotation, rotate: fn(&mut Dial, i64) -> Turn) -> Turn {
+        let name = rotation.dial.as_deref();
+        let index = match self.wheels.iter().position(|w| w.name.as_deref() == name) {
+            Some(index) => index,
+            None => {
+                self.wheels.push(Wheel {
+                    name: rotation.dial.clone(),
+                    dial: self.default,
+                    zeros: Zeros::default(),
+                });
+                self.wheels.len() - 1
+            }
+        };
+        let wheel = &mut self.wheels[index];
+        let turn = rotate(&mut wheel.dial, rotation.clicks);
+        wheel.zeros.landings += i64::from(turn.landed());
+        wheel.zeros.crossings += turn.zeros;
+        turn
+    }
+
+    /// Apply every rotation in turn, counting the zeros of all dials.
+    pub fn run(&mut self, rotations: &[Rotation]) -> Zeros {
+        for rotation in rotations {
+            self.rotate(rotation);
+        }
+        for (name, zeros) in self.zeros() {
+            debug!(dial = name.unwrap_or("-"), zeros.landings, zeros.crossings, "dial zeros");
+        }
+        self.total()
+    }
+
+    /// Zeros of each declared or turned dial, declared ones first.
+    pub fn zeros(&self) -> Vec<(Option<&str>, Zeros)> {
+        self.wheels.iter().map(|w| (w.name.as_deref(), w.zeros)).collect()
+    }
+
+    /// Zeros of the dial called `name` so far.
+    pub fn zeros_of(&self, name: Option<&str>) -> Zeros {
+        let wheel = self.wheels.iter().find(|w| w.name.as_deref() == name);
+        wheel.map_or(Zeros::default(), |w| w.zeros)
+    }
+
+    /// Zeros of all dials together.
+    pub fn total(&self) -> Zeros {
+        let mut total = Zeros::default();
+        for wheel in &self.wheels {
+            total.landings += wheel.zeros.landings;
+            total.crossings += wheel.zeros.crossings;
+        }
+        total
+    }
+}
+
+/// A dial declared like `B:60` or `B:60:3`.
+fn declare(spec: &str) -> Result<(&str, Dial), AocError> {
+    let invalid = || AocError::new(format!("invalid dial `{spec}`, expected NAME:SIZE or NAME:SIZE:START"));
+    let fields: Vec<&str> = spec.split(':').collect();
+    let (name, size, start) = match fields[..] {
+        [name, size] => (name, size, "0"),
+        [name, size, start] => (name, size, start),
+        _ => return Err(invalid()),
+    };
+    let (false, Ok(size), Ok(start)) = (name.is_empty(), size.parse(), start.parse()) else {
+        return Err(invalid());
+    };
+    let dial = Dial::new(size, start).map_err(|e| AocError::new(format!("dial `{name}`: {}", e.message)))?;
+    Ok((name, dial))
+}
+
+/// Where the turned dial points after each rotation.
+pub fn positions(rotations: &[Rotation], params: &Params) -> Result<Vec<i32>, AocError> {
+    let mut lock = Lock::from_params(params)?;
+    Ok(rotations.iter().map(|rotation| lock.rotate(rotation).position).collect())
+}
+
+/// Zeros of each dial of the lock, declared ones first.
+pub fn dial_zeros(rotations: &[Rotation], params: &Params) -> Result<Vec<(Option<String>, Zeros)>, AocError> {
+    let mut lock = Lock::from_params(params)?;
+    lock.run(rotations);
+    Ok(lock.zeros().into_iter().map(|(name, zeros)| (name.map(String::from), zeros)).collect())
+}
+
+/// Zeros of one dial's rotations from each of its starting positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Starts {
+    /// Indexed by the start
+    pub zeros: Vec<Zeros>,
+}
+
+impl Starts {
+    /// The start giving the most of `count`, the lowest start on ties.
+    pub fn best(&self, count: impl Fn(&Zeros) -> i64) -> i32 {
+        let best = self.zeros.iter().enumerate().max_by_key(|&(start, z)| (count(z), Reverse(start)));
+        best.map_or(0, |(start, _)| start as i32)
+    }
+
+    /// The start giving the least of `count`, the lowest start on ties.
+    pub fn worst(&self, count: impl Fn(&Zeros) -> i64) -> i32 {
+        let worst = self.zeros.iter().enumerate().min_by_key(|&(start, z)| (count(z), start));
+        worst.map_or(0, |(start, _)| start as i32)
+    }
+}
+
+/// Zeros of the rotations of dial `name` from every start at once. A
+/// rotation adds its whole turns to every start, and one more to the starts
+/// its last part turn carries onto 0, which make up one arc of the dial; a
+/// start lands at 0 when the clicks so far bring it round to 0.
+///
+/// ```
+/// use aoc2025::day1::{self, Day1};
+/// use aoc_core::{Params, Part, Solution};
+///
+/// let rotations = Day1::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n")?;
+/// let params = Params::defaults(Day1::PARAMS, Part::One);
+/// let starts = day1::starts(&rotations, None, &params)?;
+/// // From the puzzle's start the answers are the parts' answers
+/// assert_eq!((starts.zeros[50].landings, starts.zeros[50].crossings), (3, 6));
+/// let most = starts.best(|zeros| zeros.crossings);
+/// assert!(starts.zeros.iter().all(|z| z.crossings <= starts.zeros[most as usize].crossings));
+/// # Ok::<(), aoc_core::AocError>(())
+/// ```
+pub fn starts(
+    rotations: &[Rotation],
+    name: Option<&str>,
+    params: &Params,
+) -> Result<Starts, AocError> {
+    let size = i64::from(Lock::from_params(params)?.dial(name).size());
+    let mut landings = vec![0; size as usize];
+    // Differences between the part turns reaching 0 from neighbouring starts
+    let mut arcs = vec![0i64; size as usize + 1];
+    let mut turns = 0;
+    // Clicks turned so far, which take start `s` to `s + offset`
+    let mut offset = 0;
+    for rotation in rotations.iter().filter(|r| r.dial.as_deref() == name) {
+        let clicks = rotation.clicks;
+        turns += clicks.abs() / size;
+        let rest = clicks.abs() % size;
+        if rest > 0 {
+            // Positions `rest` or fewer clicks short of 0 in the direction of travel
+            let first = if clicks > 0 { size - rest } else { 1 };
+            let from = (first - offset).rem_euclid(size);
+            let to = from + rest;
+            arcs[from as usize] += 1;
+            if to <= size {
+                arcs[to as usize] -= 1;
+            } else {
+                arcs[0] += 1;
+                arcs[(to - size) as usize] -= 1;
+            }
+        }
+        offset = (offset + clicks % size).rem_euclid(size);
+        landings[(-offset).rem_euclid(size) as usize] += 1;
+    }
+
+    let mut crossings = turns;
+    let zeros = landings
+        .into_iter()
+        .zip(arcs)
+        .map(|(landings, arc)| {
+            crossings += arc;
+            Zeros { landings, crossings }
+        })
+        .collect();
+    Ok(Starts { zeros })
+}
+
+/// Each rotation with its line and the dial it turned, empty for the
+/// unnamed one, where that dial pointed before and after, how many times it
+/// passed or stopped at 0, and the dial's landings and crossings so far, so
+/// the last step of each dial holds its counts.
+pub fn trace(input: &str, params: &Params) -> Result<Trace, AocError> {
+    let mut lock = Lock::from_params(params)?;
+    let mut steps = Vec::new();
+    for line in parse::lines(input) {
+        let rotation = convert_string(line)?;
+        let dial = rotation.dial.as_deref();
+        let before = lock.dial(dial).position();
+        let turn = lock.rotate(&rotation);
+        let so_far = lock.zeros_of(dial);
+        steps.push(vec![
+            Field::from(line.number),
+            Field::from(dial.unwrap_or_default()),
+            Field::from(line.text),
+            Field::from(before),
+            Field::from(turn.position),
+            Field::from(turn.zeros),
+            Field::from(so_far.landings),
+            Field::from(so_far.crossings),
+        ]);
+    }
+    Ok(Trace {
+        columns: &["line", "dial", "rotation", "before", "after", "zeros", "landings", "crossings"],
+        steps,
+    })
+}
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("size", "100", "positions on the dial, numbered from 0"),
+        Param::new("start", "50", "position the dial points at first"),
+        Param::new("dials", "", "named dials of a lock, like `A:10,B:60:3` for NAME:SIZE[:START]"),
+    ];
+
+    type Input = Vec<Rotation>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines(input).into_iter().map(convert_string).collect()
+    }
+
+    fn part1(rotations: &Self::Input, params: &Params) -> Result<i64, AocError> {
+        Ok(Lock::from_params(params)?.run(rotations).landings)
+    }
+
+    fn part2(rotations: &Self::Input, params: &Params) -> Result<i64, AocError> {
+        Ok(Lock::from_params(params)?.run(rotations).crossings)
+    }
+}
+
+/// Part 2 turning the dial one click at a time.
+pub fn part2_click_by_click(input: &str, params: &Params) -> Result<String, AocError> {
+    let mut lock = Lock::from_params(params)?;
+    let crossings: i64 = Day1::parse(input)?
+        .iter()
+        .map(|rotation| lock.rotate_by_clicks(rotation).zeros)
+        .sum();
+    Ok(crossings.to_string())
+}
+
+/// `size` rotations of up to 999 clicks each.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            format!("{direction}{}\n", rng.random_range(1..1000))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Override, Part};
+    use proptest::prelude::*;
+
+    /// The puzzle's example, landing on 0 three times and passing it six.
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    /// Three named dials of a lock, declared in [`LOCK_DIALS`] but for
+    /// `C`, and the unnamed one.
+    const LOCK: &str = "A:R12\nB:L5\nR50\nA:L2\nB:R65\nC:R100\n";
+    const LOCK_DIALS: &str = "dials=A:10,B:60:3";
+
+    /// Day1's parameters with overrides like `size=60` applied.
+    fn params(overrides: &[&str]) -> Params {
+        let overrides: Vec<Override> = overrides.iter().map(|o| o.parse().unwrap()).collect();
+        Params::new(Day1::PARAMS, Part::One, &overrides).unwrap()
+    }
+
+    /// A dial, and rotations that are often whole turns of it.
+    fn dial_and_rotations() -> impl Strategy<Value = (Dial, Vec<i64>)> {
+        (1i32..200).prop_flat_map(|size| {
+            let whole_turns = (-5i64..=5).prop_map(move |turns| turns * i64::from(size));
+            let clicks = prop_oneof![-1000i64..=1000, whole_turns, -20_000i64..=20_000];
+            let dial = (0..size).prop_map(move |start| Dial::new(size, start).unwrap());
+            (dial, prop::collection::vec(clicks, 1..20))
+        })
+    }
+
+    fn rotation(text: &str) -> Result<Rotation, AocError> {
+        convert_string(parse::lines(text)[0])
+    }
+
+    #[test]
+    fn counts_are_unsigned_digits() {
+        let named = rotation("A:L12").unwrap();
+        assert_eq!((named.dial.as_deref(), named.clicks), (Some("A"), -12));
+        let signed = rotation("L-5").unwrap_err();
+        assert_eq!(signed.message, "expected a count of clicks after the direction");
+        assert_eq!(signed.location.unwrap().column, 2);
+        assert!(rotation("R+5").is_err());
+        let signed = rotation("L-2147483648").unwrap_err();
+        assert_eq!(signed.location.unwrap().column, 2);
+        assert_eq!(rotation("L2147483648").unwrap().clicks, -2_147_483_648);
+        assert_eq!(rotation("R9223372036854775807").unwrap().clicks, i64::MAX);
+        assert!(rotation("L9223372036854775808").is_err());
+    }
+
+    #[test]
+    fn whole_turns_pass_zero_once_each() {
+        // Start, clicks, and zeros passed ending back at the start
+        let turns = [
+            (0, 100, 1),
+            (0, -100, 1),
+            (50, 100, 1),
+            (50, -100, 1),
+            (0, -200, 2),
+            (0, 300, 3),
+        ];
+        for (start, clicks, zeros) in turns {
+            let turn = Turn { position: start, zeros };
+            let mut fast = Dial::new(100, start).unwrap();
+            let mut slow = fast;
+            assert_eq!(fast.rotate(clicks), turn, "{clicks} from {start}");
+            assert_eq!(slow.rotate_by_clicks(clicks), turn, "{clicks} from {start}, by clicks");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn closed_form_matches_click_by_click((dial, rotations) in dial_and_rotations()) {
+            let mut fast = dial;
+            let mut slow = dial;
+            for clicks in rotations {
+                let before = fast.position();
+                prop_assert_eq!(
+                    fast.rotate(clicks),
+                    slow.rotate_by_clicks(clicks),
+                    "rotating {} from {} on a dial of {}", clicks, before, dial.size()
+                );
+            }
+        }
+
+        #[test]
+        fn every_start_at_once_matches_each_start((dial, clicks) in dial_and_rotations()) {
+            let size = format!("size={}", dial.size());
+            let rotations: Vec<Rotation> =
+                clicks.iter().map(|&clicks| Rotation { dial: None, clicks }).collect();
+            let starts = starts(&rotations, None, &params(&[&size, "start=0"])).unwrap();
+            for start in 0..dial.size() {
+                let mut dial = Dial::new(dial.size(), start).unwrap();
+                let mut zeros = Zeros::default();
+                for &clicks in &clicks {
+                    let turn = dial.rotate(clicks);
+                    zeros.landings += i64::from(turn.landed());
+                    zeros.crossings += turn.zeros;
+                }
+                prop_assert_eq!(starts.zeros[start as usize], zeros, "from {}", start);
+            }
+        }
+    }
+
+    #[test]
+    fn large_rotations_are_whole_turns_and_the_rest() {
+        for clicks in [2_147_483_648, -2_147_483_648, 9_876_543_210, i64::MAX, -i64::MAX] {
+            let mut fast = Dial::new(100, 50).unwrap();
+            let mut slow = fast;
+            let rest = slow.rotate_by_clicks(clicks % 100);
+            let turn = Turn { position: rest.position, zeros: (clicks / 100).abs() + rest.zeros };
+            assert_eq!(fast.rotate(clicks), turn, "{clicks}");
+
+            let rotations = [Rotation { dial: None, clicks }];
+            let starts = starts(&rotations, None, &params(&[])).unwrap();
+            let zeros = Zeros { landings: i64::from(turn.landed()), crossings: turn.zeros };
+            assert_eq!(starts.zeros[50], zeros, "{clicks} from every start");
+        }
+    }
+
+    #[test]
+    fn part_turns_from_zero_do_not_count_it() {
+        let mut dial = Dial::new(100, 0).unwrap();
+        assert_eq!(dial.rotate(-5), Turn { position: 95, zeros: 0 });
+        assert_eq!(dial.rotate(5), Turn { position: 0, zeros: 1 });
+        assert_eq!(dial.rotate(5), Turn { position: 5, zeros: 0 });
+    }
+
+    #[test]
+    fn best_and_worst_starts_take_the_lowest_on_ties() {
+        let zeros = |landings, crossings| Zeros { landings, crossings };
+        let starts = Starts {
+            zeros: vec![zeros(1, 4), zeros(3, 2), zeros(0, 5), zeros(3, 5), zeros(0, 2)],
+        };
+        assert_eq!(starts.best(|z| z.landings), 1);
+        assert_eq!(starts.worst(|z| z.landings), 2);
+        assert_eq!(starts.best(|z| z.crossings), 2);
+        assert_eq!(starts.worst(|z| z.crossings), 1);
+    }
+
+    #[test]
+    fn trace_follows_the_dial() {
+        let trace = trace(EXAMPLE, &params(&[])).unwrap();
+        assert_eq!(trace.steps.len(), 10);
+        let first: Vec<String> = trace.steps[0].iter().map(Field::to_string).collect();
+        assert_eq!(first, ["1", "", "L68", "50", "82", "1", "0", "1"]);
+        let zeros: i64 = trace
+            .steps
+            .iter()
+            .map(|step| match step[5] {
+                Field::Int(zeros) => zeros,
+                Field::Text(_) => panic!("zeros should be a number"),
+            })
+            .sum();
+        assert_eq!(zeros, 6);
+    }
+
+    #[test]
+    fn lock_turns_each_dial_on_its_own() {
+        let params = params(&[LOCK_DIALS]);
+        let rotations = Day1::parse(LOCK).unwrap();
+        let zeros = |landings, crossings| Zeros { landings, crossings };
+        assert_eq!(
+            dial_zeros(&rotations, &params).unwrap(),
+            [
+                (Some("A".to_string()), zeros(1, 2)),
+                (Some("B".to_string()), zeros(0, 3)),
+                (None, zeros(1, 1)),
+                (Some("C".to_string()), zeros(0, 1)),
+            ]
+        );
+        assert_eq!(Day1::part2(&rotations, &params).unwrap(), 7);
+    }
+
+    #[test]
+    fn trace_counts_each_dial() {
+        let trace = trace(LOCK, &params(&[LOCK_DIALS])).unwrap();
+        // The last step of each dial, with its landings and crossings
+        let last = |dial: &str| {
+            let step = trace.steps.iter().rev().find(|step| step[1] == Field::from(dial)).unwrap();
+            (step[6].clone(), step[7].clone())
+        };
+        assert_eq!(last("A"), (Field::Int(1), Field::Int(2)));
+        assert_eq!(last("B"), (Field::Int(0), Field::Int(3)));
+        assert_eq!(last(""), (Field::Int(1), Field::Int(1)));
+        assert_eq!(last("C"), (Field::Int(0), Field::Int(1)));
+    }
+}
@@ -0,0 +1,240 @@
+use aoc_core::parse::{self, Line};
+use aoc_core::{AocError, InputRng, Params, Solution};
+use microlp::{ComparisonOp, LinearExpr, OptimizationDirection, Problem};
+use rand::Rng;
+use rand::seq::index;
+use std::collections::{HashSet, VecDeque};
+use tracing::trace;
+
+pub struct Day10;
+
+#[derive(Debug)]
+pub struct Factory {
+    /// Indicator light pattern between `[]`, 1 for `#`
+    pub lights: Vec<usize>,
+    /// Counters touched by each button, as 0/1 per position
+    pub buttons: Vec<Vec<usize>>,
+    /// Joltage requirements between `{}`
+    pub joltage: Vec<usize>,
+}
+
+fn combine_buttons(result: &[usize], buttons: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let n = buttons.len();
+
+    // BFS: (current_state, used_buttons_mask)
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    // Start with empty state (all zeros)
+    queue.push_back((vec![0; result.len()], 0u64));
+    visited.insert(0u64);
+
+    while let Some((current, mask)) = queue.pop_front() {
+        // Check if we reached the target
+        if current == result {
+            // Extract which buttons were used from the mask
+            let indices: Vec<usize> = (0..n).filter(|&i| mask & (1 << i) != 0).collect();
+            return Some(indices);
+        }
+
+        // Try pressing each unused button
+        for (i, button) in buttons.iter().enumerate() {
+            if mask & (1 << i) != 0 {
+                continue;
+            }
+
+            // XOR current state with button[i]
+            let next_state: Vec<usize> = current
+                .iter()
+                .zip(button.iter())
+                .map(|(a, b)| a ^ b)
+                .collect();
+
+            let next_mask = mask | (1 << i);
+
+            if visited.insert(next_mask) {
+                queue.push_back((next_state, next_mask));
+            }
+        }
+    }
+
+    None
+}
+
+fn solve_ilp(result: &[usize], buttons: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let num_positions = result.len();
+
+    // Objective: minimize total button presses
+    let mut problem = Problem::new(OptimizationDirection::Minimize);
+    let button_vars: Vec<_> = buttons
+        .iter()
+        .map(|_| problem.add_integer_var(1.0, (0, i32::MAX)))
+        .collect();
+
+    // Add constraints: for each position
+    for pos in 0..num_positions {
+        let mut constraint_expr = LinearExpr::empty();
+        for (btn_idx, &var) in button_vars.iter().enumerate() {
+            constraint_expr.add(var, buttons[btn_idx][pos] as f64);
+        }
+        problem.add_constraint(constraint_expr, ComparisonOp::Eq, result[pos] as f64);
+    }
+
+    let solution = problem.solve().ok()?;
+
+    let coefficients: Vec<usize> = button_vars
+        .iter()
+        .map(|&v| solution[v].round() as usize)
+        .collect();
+
+    trace!(?coefficients, "button presses");
+
+    for pos in 0..num_positions {
+        let sum: usize = coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| c * buttons[i][pos])
+            .sum();
+        if sum != result[pos] {
+            return None;
+        }
+    }
+
+    Some(coefficients)
+}
+
+fn parse_factory(line: Line) -> Result<Factory, AocError> {
+    let groups = line.groups()?;
+    let (Some(first), Some(last)) = (groups.first(), groups.last()) else {
+        return Err(line.error_line("expected a machine like `[.##.] (3) (1,3) {3,5,4,7}`"));
+    };
+    if first.open != '[' {
+        return Err(line.error(first.body, "expected a light pattern in `[]` first"));
+    }
+    if last.open != '{' {
+        return Err(line.error(last.body, "expected joltage requirements in `{}` last"));
+    }
+
+    let pattern = first.body;
+    let lights: Vec<usize> = pattern
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| match ch {
+            '#' => Ok(1),
+            '.' => Ok(0),
+            _ => Err(AocError::at(line.number, line.column(pattern) + i, ch, "expected `#` or `.`")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let joltage: Vec<usize> = line.list(last.body, ',')?;
+    if joltage.len() != lights.len() {
+        let message = format!("{} joltage requirements for {} lights", joltage.len(), lights.len());
+        return Err(line.error(last.body, message));
+    }
+
+    // Buttons are every group between the pattern and the requirements
+    let mut buttons: Vec<Vec<usize>> = Vec::new();
+    for group in &groups[1..groups.len() - 1] {
+        if group.open != '(' {
+            return Err(line.error(group.body, "expected a button like `(0,2)`"));
+        }
+        let indices: Vec<usize> = line.list(group.body, ',')?;
+
+        if let Some(&index) = indices.iter().find(|&&i| i >= lights.len()) {
+            let message = format!("button wires counter {index} but there are only {}", lights.len());
+            return Err(line.error(group.body, message));
+        }
+
+        // Create vec and mark indices as 1
+        let binary = (0..lights.len())
+            .map(|i| if indices.contains(&i) { 1 } else { 0 })
+            .collect();
+
+        buttons.push(binary);
+    }
+    if buttons.len() > 64 {
+        return Err(line.error_line("more than 64 buttons"));
+    }
+
+    Ok(Factory {
+        lights,
+        buttons,
+        joltage,
+    })
+}
+
+fn unsolvable(index: usize, message: &str) -> AocError {
+    AocError::new(format!("machine on line {}: {message}", index + 1))
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<Factory>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines(input).into_iter().map(parse_factory).collect()
+    }
+
+    fn part1(list: &Self::Input, _: &Params) -> Result<usize, AocError> {
+        let mut result = 0;
+        for (i, factory) in list.iter().enumerate() {
+            let Some(pressed) = combine_buttons(&factory.lights, &factory.buttons) else {
+                return Err(unsolvable(i, "no buttons combination lights the pattern"));
+            };
+            result += pressed.len();
+        }
+        Ok(result)
+    }
+
+    fn part2(list: &Self::Input, _: &Params) -> Result<usize, AocError> {
+        let mut result = 0;
+        for (i, factory) in list.iter().enumerate() {
+            let Some(coefficients) = solve_ilp(&factory.joltage, &factory.buttons) else {
+                return Err(unsolvable(i, "no button presses reach the joltage requirements"));
+            };
+            result += coefficients.iter().sum::<usize>();
+        }
+        Ok(result)
+    }
+}
+
+/// `size` machines of 4 to 10 lights. The lights come from pressing a random
+/// set of buttons once and the joltages from random press counts, so both
+/// parts always have a solution.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let lights = rng.random_range(4..=10);
+        let buttons: Vec<Vec<usize>> = (0..rng.random_range(3..=12))
+            .map(|_| {
+                let wired = rng.random_range(1..=lights / 2 + 1);
+                let mut counters = index::sample(rng, lights, wired).into_vec();
+                counters.sort();
+                counters
+            })
+            .collect();
+
+        let mut pattern = vec![false; lights];
+        let mut joltage = vec![0; lights];
+        for button in &buttons {
+            let toggled = rng.random_bool(0.5);
+            let presses = rng.random_range(0..20);
+            for &counter in button {
+                pattern[counter] ^= toggled;
+                joltage[counter] += presses;
+            }
+        }
+
+        let pattern: String = pattern.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        let buttons: Vec<String> = buttons
+            .iter()
+            .map(|b| format!("({})", b.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(",")))
+            .collect();
+        let joltage: Vec<String> = joltage.iter().map(|j| j.to_string()).collect();
+        input += &format!("[{pattern}] {} {{{}}}\n", buttons.join(" "), joltage.join(","));
+    }
+    input
+}
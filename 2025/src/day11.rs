@@ -0,0 +1,371 @@
+use aoc_core::parse;
+use aoc_core::{Answer, AocError, InputRng, Param, Params, Solution};
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day11;
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub name: String,
+    pub connections: Vec<String>,
+}
+
+/// Names of the devices the puzzle asks about, from the day's parameters.
+#[derive(Debug, Clone)]
+pub struct Devices {
+    pub you: String,
+    pub svr: String,
+    pub dac: String,
+    pub fft: String,
+    pub out: String,
+}
+
+impl Devices {
+    pub fn new(params: &Params) -> Result<Self, AocError> {
+        Ok(Devices {
+            you: params.get("you")?,
+            svr: params.get("svr")?,
+            dac: params.get("dac")?,
+            fft: params.get("fft")?,
+            out: params.get("out")?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    pub nodes: HashMap<String, Node>,
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Graph {
+            nodes: HashMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, name: String, connections: Vec<String>) {
+        self.nodes.insert(name.clone(), Node { name, connections });
+    }
+
+    pub fn get_connections(&self, node: &str) -> Option<&Vec<String>> {
+        self.nodes.get(node).map(|n| &n.connections)
+    }
+
+    pub fn find_paths_from_start_bfs(&self, start: &str, end: &str) -> HashSet<Vec<String>> {
+        let mut all_paths = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        // Start with the initial node
+        queue.push_back(vec![start.to_string()]);
+
+        while let Some(path) = queue.pop_front() {
+            let current = path.last().unwrap();
+
+            // Check if we've reached the end
+            if current == end {
+                all_paths.insert(path.clone());
+                continue;
+            }
+
+            // Explore all connections
+            if let Some(connections) = self.get_connections(current) {
+                for conn in connections {
+                    // Skip if already in path (avoid cycles)
+                    if !path.contains(conn) {
+                        let mut new_path = path.clone();
+                        new_path.push(conn.clone());
+                        queue.push_back(new_path);
+                    }
+                }
+            }
+        }
+
+        all_paths
+    }
+
+    pub fn find_paths_from_start_dfs(
+        &self,
+        start: &str,
+        devices: &Devices,
+    ) -> HashSet<Vec<String>> {
+        let mut all_paths = HashSet::new();
+        let mut stack = Vec::new();
+
+        // Track (path, visited_set) to avoid O(n) contains checks
+        let mut initial_visited = HashSet::new();
+        initial_visited.insert(start.to_string());
+        stack.push((vec![start.to_string()], initial_visited));
+
+        while let Some((path, visited)) = stack.pop() {
+            let current = path.last().unwrap();
+
+            if *current == devices.out {
+                let has_dac = path.contains(&devices.dac);
+                let has_fft = path.contains(&devices.fft);
+
+                if has_dac && has_fft {
+                    all_paths.insert(path.clone());
+                }
+                continue;
+            }
+
+            if let Some(connections) = self.get_connections(current) {
+                for conn in connections {
+                    // O(1) cycle detection with HashSet
+                    if !visited.contains(conn) {
+                        let mut new_path = path.clone();
+                        new_path.push(conn.clone());
+
+                        let mut new_visited = visited.clone();
+                        new_visited.insert(conn.clone());
+
+                        stack.push((new_path, new_visited));
+                    }
+                }
+            }
+        }
+        all_paths
+    }
+
+    pub fn count_paths_dfs(&self, start: &str, devices: &Devices) -> Result<Answer, AocError> {
+        let step = "paths through dac and fft";
+        let Devices { dac, fft, out, .. } = devices;
+        // Count paths through dac first, then fft
+        let count1 = self
+            .count_segment_paths(start, dac)?
+            .mul(self.count_segment_paths(dac, fft)?, step)?
+            .mul(self.count_segment_paths(fft, out)?, step)?;
+
+        // Count paths through fft first, then dac
+        let count2 = self
+            .count_segment_paths(start, fft)?
+            .mul(self.count_segment_paths(fft, dac)?, step)?
+            .mul(self.count_segment_paths(dac, out)?, step)?;
+
+        count1.add(count2, step)
+    }
+
+    /// Paths from `start` to `end`, counted without recursion so that
+    /// long chains of devices cannot overflow the stack.
+    pub fn count_segment_paths(&self, start: &str, end: &str) -> Result<Answer, AocError> {
+        let outputs = |device: &str| match device == end {
+            true => &[][..],
+            false => self.get_connections(device).map_or(&[][..], Vec::as_slice),
+        };
+
+        // Devices reachable from `start` in depth-first postorder, so each
+        // one comes after every device it feeds
+        let mut order = Vec::new();
+        let mut seen = HashSet::from([start]);
+        let mut stack = vec![(start, 0)];
+        while let Some(&(current, next)) = stack.last() {
+            match outputs(current).get(next) {
+                Some(conn) => {
+                    stack.last_mut().unwrap().1 += 1;
+                    if seen.insert(conn) {
+                        stack.push((conn, 0));
+                    }
+                }
+                None => {
+                    order.push(current);
+                    stack.pop();
+                }
+            }
+        }
+
+        // The device graph is acyclic, so every device a device feeds has
+        // its count by the time it is reached
+        let mut paths: HashMap<&str, Answer> = HashMap::new();
+        for device in order {
+            let count = match device == end {
+                true => Answer::ONE,
+                false => outputs(device).iter().try_fold(Answer::ZERO, |total, conn| {
+                    total.add(paths.get(conn.as_str()).cloned().unwrap_or_default(), "path count")
+                })?,
+            };
+            paths.insert(device, count);
+        }
+        Ok(paths.remove(start).unwrap_or_default())
+    }
+
+    pub fn dfs_count(
+        &self,
+        current: &str,
+        devices: &Devices,
+        visited: &mut HashSet<String>,
+        has_dac: bool,
+        has_fft: bool,
+        count: &mut usize,
+    ) {
+        if current == devices.out {
+            if has_dac && has_fft {
+                *count += 1;
+            }
+            return;
+        }
+
+        visited.insert(current.to_string());
+
+        let new_has_dac = has_dac || current == devices.dac;
+        let new_has_fft = has_fft || current == devices.fft;
+
+        if let Some(connections) = self.get_connections(current) {
+            for conn in connections {
+                if !visited.contains(conn) {
+                    self.dfs_count(conn, devices, visited, new_has_dac, new_has_fft, count);
+                }
+            }
+        }
+
+        visited.remove(current);
+    }
+}
+
+/// Part 1 counting paths with the memoised segment search instead of
+/// listing every one of them.
+pub fn part1_segment_count(input: &str, params: &Params) -> Result<String, AocError> {
+    let graph = Day11::parse(input)?;
+    let devices = Devices::new(params)?;
+    Ok(graph
+        .count_segment_paths(&devices.you, &devices.out)?
+        .to_string())
+}
+
+/// Part 2 by brute force, walking every path and tracking `dac` and `fft`.
+pub fn part2_dfs_count(input: &str, params: &Params) -> Result<String, AocError> {
+    let graph = Day11::parse(input)?;
+    let devices = Devices::new(params)?;
+    let mut count = 0;
+    graph.dfs_count(
+        &devices.svr,
+        &devices,
+        &mut HashSet::new(),
+        false,
+        false,
+        &mut count,
+    );
+    Ok(count.to_string())
+}
+
+/// Part 2 listing every path through `dac` and `fft`.
+pub fn part2_all_paths(input: &str, params: &Params) -> Result<String, AocError> {
+    let graph = Day11::parse(input)?;
+    let devices = Devices::new(params)?;
+    Ok(graph
+        .find_paths_from_start_dfs(&devices.svr, &devices)
+        .len()
+        .to_string())
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("you", "you", "device part 1 counts paths from"),
+        Param::new("svr", "svr", "device part 2 counts paths from"),
+        Param::new("dac", "dac", "first device part 2 paths must visit"),
+        Param::new("fft", "fft", "second device part 2 paths must visit"),
+        Param::new("out", "out", "device every path ends at"),
+    ];
+
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut graph = Graph::new();
+
+        for line in parse::lines(input) {
+            let (node_name, connections) = line.adjacency()?;
+            let connections = connections.into_iter().map(|s| s.to_string()).collect();
+
+            graph.add_node(node_name.to_string(), connections);
+        }
+        Ok(graph)
+    }
+
+    fn part1(graph: &Self::Input, params: &Params) -> Result<usize, AocError> {
+        let devices = Devices::new(params)?;
+        Ok(graph
+            .find_paths_from_start_bfs(&devices.you, &devices.out)
+            .len())
+    }
+
+    fn part2(graph: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let devices = Devices::new(params)?;
+        graph.count_paths_dfs(&devices.svr, &devices)
+    }
+}
+
+/// A network of `size` devices with no cycles. Devices only feed devices
+/// shortly after them in a shuffled order, `svr` comes first, `out` last,
+/// and `you` close to the end so part 1 stays small enough to list. One
+/// path always runs from `svr` through `dac` and then `fft` to `out`.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    const REACH: usize = 12;
+    // Three letters like the real inputs, more once there are not plenty
+    // of those to pick from
+    let mut letters = 3;
+    while 26usize.checked_pow(letters).is_some_and(|names| names / 2 < size) {
+        letters += 1;
+    }
+    let mut taken: HashSet<String> = ["svr", "you", "dac", "fft", "out"].map(String::from).into();
+    let mut names: Vec<String> = Vec::new();
+    while names.len() + 5 < size {
+        let name: String = (0..letters).map(|_| char::from(rng.random_range(b'a'..=b'z'))).collect();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names.push("dac".to_string());
+    names.push("fft".to_string());
+    names.shuffle(rng);
+    let you = names.len() - rng.random_range(0..names.len().min(REACH));
+    names.insert(you, "you".to_string());
+    names.insert(0, "svr".to_string());
+    names.push("out".to_string());
+    let position = |name: &str| names.iter().position(|n| n == name).unwrap();
+    let (dac, fft) = (position("dac"), position("fft"));
+    if dac > fft {
+        names.swap(dac, fft);
+    }
+
+    // The hop each device on the guaranteed path takes, never further than
+    // the reach of the other outputs
+    let mut hops = HashMap::new();
+    let waypoints = [0, dac.min(fft), dac.max(fft), names.len() - 1];
+    for pair in waypoints.windows(2) {
+        let mut i = pair[0];
+        while i < pair[1] {
+            let hop = rng.random_range(i + 1..=(i + REACH).min(pair[1]));
+            hops.insert(i, hop);
+            i = hop;
+        }
+    }
+
+    let mut input = String::new();
+    for (i, name) in names.iter().enumerate().take(names.len() - 1) {
+        let later = &names[i + 1..(i + 1 + REACH).min(names.len())];
+        let outputs = match rng.random_range(0..10) {
+            0..6 => 1,
+            6..9 => 2,
+            _ => 3,
+        };
+        let hop = hops.get(&i).map(|&hop| names[hop].as_str());
+        let others: Vec<&str> = later.iter().map(String::as_str).filter(|&n| Some(n) != hop).collect();
+        let mut targets: Vec<&str> = hop.into_iter().collect();
+        targets.extend(others.choose_multiple(rng, outputs - targets.len()));
+        targets.shuffle(rng);
+        input += &format!("{name}: {}\n", targets.join(" "));
+    }
+    input
+}
@@ -0,0 +1,142 @@
+use aoc_core::parse;
+use aoc_core::{Answer, AocError, InputRng, Params, Solution};
+use rand::Rng;
+
+pub struct Day2;
+
+fn is_repeated(s: &str) -> bool {
+    let len = s.len();
+    match len {
+        2 => s[0..1] == s[1..2],
+        l if l % 2 == 0 => {
+            if (s[0..2].repeat(l / 2) == s)
+                || (s[0..(l / 2)].repeat(2) == s)
+                || (s[0..(l / 4)].repeat(4) == s)
+            {
+                true
+            } else if (l % 5 == 0) && (l > 5) {
+                s[0..5].repeat(l / 5) == s
+            } else {
+                false
+            }
+        }
+        l if l % 3 == 0 && l > 3 => s[0..3].repeat(l / 3) == s,
+        1 => false,
+        _ => s[0..1].repeat(len) == s,
+    }
+}
+
+fn is_repeated_log10(s: &str) -> Result<bool, AocError> {
+    // Elegant solution proposed in reddit forum,
+    // it's based on the integer log base 10
+    // to get the number lenght
+    let i: u64 = s
+        .parse()
+        .map_err(|e| AocError::new(format!("invalid ID `{s}`: {e}")))?;
+    Ok(match 1 + i.checked_ilog10().unwrap_or(0) {
+        1 => false,
+        2 => i.is_multiple_of(11),
+        3 => i.is_multiple_of(111),
+        4 => i.is_multiple_of(101),
+        5 => i.is_multiple_of(11111),
+        6 => i.is_multiple_of(1001) || i.is_multiple_of(10101),
+        7 => i.is_multiple_of(1111111),
+        8 => i.is_multiple_of(1010101) || i.is_multiple_of(10001),
+        9 => i.is_multiple_of(1001001),
+        10 => i.is_multiple_of(101010101) || i.is_multiple_of(100001),
+        _ => {
+            let message = format!("ID {i} has more than the 10 digits the log10 check handles");
+            return Err(AocError::new(message));
+        }
+    })
+}
+
+fn is_doubled(s: &str) -> bool {
+    // Part one only counts a sequence repeated exactly twice
+    let len = s.len();
+    len.is_multiple_of(2) && s[..len / 2] == s[len / 2..]
+}
+
+fn sum_matching(
+    ranges: &[(i64, i64)],
+    matches: impl Fn(&str) -> Result<bool, AocError>,
+) -> Result<Answer, AocError> {
+    let mut result = Answer::ZERO;
+    for &(start, end) in ranges {
+        for i in start..=end {
+            let s = i.to_string();
+            if matches(&s)? {
+                result = result.add(i, "sum of invalid IDs")?;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Part 2 with the log10 based check, for IDs of up to 10 digits; longer
+/// ones are an error.
+pub fn part2_log10(input: &str, _: &Params) -> Result<String, AocError> {
+    let ranges = Day2::parse(input)?;
+    Ok(sum_matching(&ranges, is_repeated_log10)?.to_string())
+}
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<(i64, i64)>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut ranges = Vec::new();
+        for line in parse::lines(input) {
+            for range in line.text.split(',').map(str::trim) {
+                // A trailing comma at the end of a wrapped line
+                if range.is_empty() {
+                    continue;
+                }
+                let (start, end) = line.split_once(range, '-', "a range like `11-22`")?;
+                ranges.push((line.number(start)?, line.number(end)?));
+            }
+        }
+        Ok(ranges)
+    }
+
+    fn part1(ranges: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        sum_matching(ranges, |s| Ok(is_doubled(s)))
+    }
+
+    fn part2(ranges: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        sum_matching(ranges, |s| Ok(is_repeated(s)))
+    }
+}
+
+/// `size` ranges of IDs with 1 to 10 digits, each range keeping the same
+/// number of digits throughout.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.random_range(1..=10);
+            let low = 10u64.pow(digits - 1);
+            let high = 10u64.pow(digits) - 1;
+            let start = rng.random_range(low..=high);
+            let end = (start + rng.random_range(0..2000)).min(high);
+            format!("{start}-{end}")
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+
+    #[test]
+    fn log10_check_handles_zero_and_rejects_long_ids() {
+        let params = Params::defaults(Day2::PARAMS, Part::Two);
+        assert_eq!(part2_log10("0-22\n", &params).unwrap(), "33");
+        let e = part2_log10("99999999999-99999999999\n", &params).unwrap_err();
+        assert!(e.to_string().contains("more than the 10 digits"), "{e}");
+    }
+}
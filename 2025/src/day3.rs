@@ -0,0 +1,82 @@
+use aoc_core::parse;
+use aoc_core::{Answer, AocError, InputRng, Param, Params, Solution};
+use rand::Rng;
+
+pub struct Day3;
+
+fn joltage(numbers: &[i64], mut k: usize) -> Result<Answer, AocError> {
+    // Callers make sure there are at least k batteries
+    // Find largest number with the min index number
+    // which is not at the last position
+    // second largest number starting from the index of the max
+    let len = numbers.len();
+    let mut result: Vec<i64> = vec![];
+
+    let mut current_index = 0;
+    while k > 0 && current_index < len {
+        let max = numbers[current_index..len - k + 1].iter().max().unwrap();
+        // Find the first (minimum) index of that max value
+        let max_index = numbers[current_index..len - k + 1]
+            .iter()
+            .position(|x| x == max)
+            .unwrap();
+        result.push(*max);
+        // Convert to absolute index in the original array
+        current_index = current_index + max_index + 1;
+        k -= 1;
+    }
+    // Convert array [4, 2, 7] to number 427
+    result
+        .iter()
+        .try_fold(Answer::ZERO, |acc, &digit| acc.mul(10i64, "joltage")?.add(digit, "joltage"))
+}
+
+fn total_joltage(digit_arrays: &[Vec<i64>], k: usize) -> Result<Answer, AocError> {
+    let mut result = Answer::ZERO;
+    for (i, d) in digit_arrays.iter().enumerate() {
+        if d.len() < k {
+            let bank: String = d.iter().map(|digit| digit.to_string()).collect();
+            return Err(AocError::at(i + 1, 1, bank, format!("bank has fewer than {k} batteries")));
+        }
+        result = result.add(joltage(d, k)?, "total joltage")?;
+    }
+    Ok(result)
+}
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    const PARAMS: &'static [Param] = &[
+        Param::per_part("k", "2", "12", "batteries turned on in each bank"),
+    ];
+
+    type Input = Vec<Vec<i64>>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // Parse lines as digits into Vec<i64>
+        parse::lines(input)
+            .into_iter()
+            .map(|line| Ok(line.digits()?.into_iter().map(i64::from).collect()))
+            .collect()
+    }
+
+    fn part1(digit_arrays: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        total_joltage(digit_arrays, params.get("k")?)
+    }
+
+    fn part2(digit_arrays: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        total_joltage(digit_arrays, params.get("k")?)
+    }
+}
+
+/// `size` banks of 100 batteries rated 1 to 9.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        input.extend((0..100).map(|_| char::from(b'0' + rng.random_range(1..=9))));
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,81 @@
+use aoc_core::parse;
+use aoc_core::{AocError, Grid, InputRng, Param, Params, Pos, Solution};
+use rand::Rng;
+
+pub struct Day4;
+
+const ROLL: char = '@';
+const EMPTY: char = '.';
+
+/// Rolls of paper in the eight cells around `pos`.
+fn adjacent(pos: Pos, grid: &Grid<char>) -> usize {
+    grid.neighbours8(pos).filter(|&n| grid[n] == ROLL).count()
+}
+
+/// Whether a forklift can reach the roll at `pos`: fewer than `threshold`
+/// rolls around it.
+fn accessible(pos: Pos, grid: &Grid<char>, threshold: usize) -> bool {
+    grid[pos] == ROLL && adjacent(pos, grid) < threshold
+}
+
+fn remove_accessible(grid: &mut Grid<char>, threshold: usize) -> usize {
+    let mut result = 0;
+    for pos in grid.positions() {
+        if accessible(pos, grid, threshold) {
+            grid[pos] = EMPTY;
+            result += 1;
+        }
+    }
+    result
+}
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    const PARAMS: &'static [Param] = &[Param::new(
+        "threshold",
+        "4",
+        "rolls around a roll that keep a forklift from reaching it",
+    )];
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let cell = |c| [ROLL, EMPTY].contains(&c).then_some(c);
+        parse::grid(input, cell, "`@` or `.`")
+    }
+
+    fn part1(grid: &Self::Input, params: &Params) -> Result<usize, AocError> {
+        let threshold = params.get("threshold")?;
+        let accessible = grid
+            .positions()
+            .filter(|&pos| accessible(pos, grid, threshold))
+            .count();
+        Ok(accessible)
+    }
+
+    fn part2(grid: &Self::Input, params: &Params) -> Result<usize, AocError> {
+        let threshold = params.get("threshold")?;
+        let mut matrix = grid.clone();
+        let mut num_changes = 0;
+        loop {
+            let changes = remove_accessible(&mut matrix, threshold);
+            num_changes += changes;
+            if changes == 0 {
+                break Ok(num_changes);
+            }
+        }
+    }
+}
+
+/// A `size` by `size` floor, about 60% covered in rolls.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        input.extend((0..size.max(1)).map(|_| if rng.random_bool(0.6) { ROLL } else { EMPTY }));
+        input.push('\n');
+    }
+    input
+}
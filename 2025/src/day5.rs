@@ -0,0 +1,148 @@
+use aoc_core::parse;
+use aoc_core::{Answer, AocError, InputRng, Params, Solution};
+use rand::Rng;
+use std::collections::HashSet;
+
+pub struct Day5;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FreshInfo {
+    pub start: i64,
+    pub end: i64,
+}
+
+pub struct Inventory {
+    pub fresh: Vec<FreshInfo>,
+    pub ingredients: Vec<i64>,
+}
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Inventory;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // Split by empty line
+        let sections = parse::sections(input);
+        let [ranges, ids] = &sections[..] else {
+            return Err(AocError::new("expected fresh ranges and ingredient IDs separated by a blank line"));
+        };
+
+        // First list (before empty line)
+        let fresh = ranges
+            .iter()
+            .map(|line| {
+                let (start, end) = line.split_once(line.text, '-', "a range like `3-5`")?;
+                let fresh = FreshInfo {
+                    start: line.number(start)?,
+                    end: line.number(end)?,
+                };
+                if fresh.start > fresh.end {
+                    return Err(line.error(end, "range ends before it starts"));
+                }
+                Ok(fresh)
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        // Second list (after empty line)
+        let ingredients = ids
+            .iter()
+            .map(|line| line.number(line.text.trim_start()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Inventory { fresh, ingredients })
+    }
+
+    fn part1(inventory: &Self::Input, _: &Params) -> Result<usize, AocError> {
+        let mut result: HashSet<i64> = HashSet::new();
+        for &id in inventory.ingredients.iter() {
+            for f in inventory.fresh.iter() {
+                if id >= f.start && id <= f.end {
+                    result.insert(id);
+                }
+            }
+        }
+        Ok(result.len())
+    }
+
+    fn part2(inventory: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let mut fresh_infos = inventory.fresh.clone();
+        fresh_infos.sort();
+        if fresh_infos.is_empty() {
+            return Ok(Answer::ZERO);
+        }
+
+        let mut stack: Vec<FreshInfo> = Vec::new();
+        stack.push(fresh_infos[0].clone());
+        for fi in fresh_infos.iter() {
+            let last = stack.last_mut().unwrap();
+            // Check all overlap cases
+            if fi.start >= last.start && fi.start <= last.end {
+                last.end = last.end.max(fi.end);
+            } else {
+                stack.push(fi.clone());
+            }
+        }
+        stack.iter().try_fold(Answer::ZERO, |total, s| {
+            let ids = Answer::from(s.end).sub(s.start, "range size")?.add(1i64, "range size")?;
+            total.add(ids, "fresh IDs")
+        })
+    }
+}
+
+/// `size` fresh ranges followed by `size` ingredient IDs, about half of
+/// them picked from inside a range.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let size = size.max(1);
+    let ranges: Vec<(i64, i64)> = (0..size)
+        .map(|_| {
+            let start = rng.random_range(1..500_000_000_000_000);
+            (start, start + rng.random_range(0..1_000_000_000_000))
+        })
+        .collect();
+
+    let mut input = String::new();
+    for (start, end) in &ranges {
+        input += &format!("{start}-{end}\n");
+    }
+    input.push('\n');
+    for _ in 0..size {
+        let id = if rng.random_bool(0.5) {
+            let (start, end) = ranges[rng.random_range(0..ranges.len())];
+            rng.random_range(start..=end)
+        } else {
+            rng.random_range(1..510_000_000_000_000)
+        };
+        input += &format!("{id}\n");
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+
+    #[test]
+    fn reversed_ranges_are_rejected() {
+        let Err(e) = Day5::parse("1-2\n3-1\n\n2\n") else {
+            panic!("`3-1` should be rejected");
+        };
+        assert_eq!(e.to_string(), "line 2, column 3: range ends before it starts (found `1`)");
+        assert!(Day5::parse("3-3\n\n3\n").is_ok());
+    }
+
+    #[test]
+    fn the_widest_range_overflows_safely() {
+        let Ok(inventory) = Day5::parse("0-9223372036854775807\n\n1\n") else {
+            panic!("the range should parse");
+        };
+        // Checked in debug builds, promoted in release ones; either way no panic
+        match Day5::part2(&inventory, &Params::defaults(Day5::PARAMS, Part::Two)) {
+            Ok(fresh) => assert_eq!(fresh.to_string(), "9223372036854775808"),
+            Err(e) => assert!(e.to_string().starts_with("overflow in range size"), "{e}"),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use aoc_core::error;
+use aoc_core::parse::{self, Line};
+use aoc_core::{Answer, AocError, InputRng, Params, Solution};
+use rand::Rng;
+use tracing::trace;
+
+pub struct Day6;
+
+const OPERATORS: [&str; 4] = ["+", "*", "-", "/"];
+
+/// Apply `op` down a column of numbers. `at` makes an error about the
+/// number at an index of the column, for a division by zero.
+fn apply_operation(
+    numbers: &[i64],
+    op: &str,
+    at: impl Fn(usize, &str) -> AocError,
+) -> Result<Answer, AocError> {
+    match op {
+        "+" => numbers.iter().try_fold(Answer::ZERO, |acc, &x| acc.add(x, "column sum")),
+        "*" => numbers.iter().try_fold(Answer::ONE, |acc, &x| acc.mul(x, "column product")),
+        "-" => numbers[1..]
+            .iter()
+            .try_fold(Answer::from(numbers[0]), |acc, &x| acc.sub(x, "column difference")),
+        "/" => {
+            let mut quotient = numbers[0];
+            for (i, &x) in numbers.iter().enumerate().skip(1) {
+                if x == 0 {
+                    return Err(at(i, "division by zero"));
+                }
+                let Some(next) = quotient.checked_div(x) else {
+                    let message = format!(
+                        "overflow in column quotient: {quotient} / {x} does not fit in 64 bits"
+                    );
+                    return Err(AocError::new(message));
+                };
+                quotient = next;
+            }
+            Ok(quotient.into())
+        }
+        _ => unreachable!("operators are checked while parsing"),
+    }
+}
+
+fn check_row(line: &Line, operators: usize) -> Result<(), AocError> {
+    let numbers = line.text.split_whitespace().collect::<Vec<_>>();
+    for number in &numbers {
+        line.number::<i64>(number)?;
+    }
+    if numbers.len() != operators {
+        let message = format!("row has {} numbers but there are {operators} operators", numbers.len());
+        return Err(line.error_line(message));
+    }
+    Ok(())
+}
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    // The worksheet is read row by row for part 1 but column by column
+    // for part 2, so keep the raw lines with their alignment intact.
+    type Input = Vec<String>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let lines = parse::lines(input);
+        let Some((operator_line, number_lines)) = lines.split_last() else {
+            return Err(AocError::new("the worksheet is empty"));
+        };
+        if number_lines.is_empty() {
+            return Err(AocError::new("the worksheet has no rows of numbers"));
+        }
+
+        let operators = operator_line.text.split_whitespace().collect::<Vec<_>>();
+        if operators.is_empty() {
+            return Err(operator_line.error_line("expected a row of operators"));
+        }
+        for op in &operators {
+            if !OPERATORS.contains(op) {
+                return Err(operator_line.error(op, "unknown operator"));
+            }
+        }
+        for line in number_lines {
+            check_row(line, operators.len())?;
+        }
+
+        // Keep the untrimmed text: trailing spaces on the operator row still
+        // cover the columns of the last problem.
+        Ok(input.lines().take(lines.len()).map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let operations = lines[lines.len() - 1]
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>();
+        let num_cols = lines[0].split_whitespace().count();
+        let number_lines = &lines[..lines.len() - 1];
+        // Splits by any whitespace (space, tab); the tokens stay slices of
+        // their line so errors can point at them
+        let rows: Vec<Vec<&str>> = number_lines
+            .iter()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        let mut l = 0;
+        let mut result = Answer::ZERO;
+        loop {
+            let at = |row: usize, message: &str| {
+                AocError::in_line(row + 1, &number_lines[row], rows[row][l], message)
+            };
+            let aux: Vec<i64> = rows
+                .iter()
+                .enumerate()
+                .map(|(row, tokens)| error::number(row + 1, &number_lines[row], tokens[l]))
+                .collect::<Result<_, _>>()?;
+            let op = &operations[l];
+            let value = apply_operation(&aux, op, at)?;
+            trace!(column = l, numbers = ?aux, op, %value, "solved problem");
+            result = result.add(value, "worksheet total")?;
+            l += 1;
+            if l >= num_cols {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
+    fn part2(lines: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let mut numbers: Vec<_> = lines[..lines.len() - 1]
+            .iter()
+            .map(|line| line.chars())
+            .collect();
+        let operators = lines.last().unwrap().chars();
+
+        let mut result = Answer::ZERO;
+        let mut curr = Answer::ZERO;
+        let mut curr_op: char = ' ';
+        for (col, operator) in operators.enumerate() {
+            match operator {
+                '+' => {
+                    result = result.add(curr, "worksheet total")?;
+                    curr = Answer::ZERO;
+                    curr_op = operator;
+                }
+
+                '*' => {
+                    result = result.add(curr, "worksheet total")?;
+                    curr = Answer::ONE;
+                    curr_op = operator;
+                }
+
+                ' ' => {}
+
+                other => {
+                    let message = "only `+` and `*` can be read column by column";
+                    return Err(AocError::at(lines.len(), col + 1, other, message));
+                }
+            }
+
+            let digits = numbers
+                .iter_mut()
+                .filter_map(|number| number.next())
+                .filter_map(|x| x.to_digit(10));
+            let mut operand: Option<Answer> = None;
+            for digit in digits {
+                let shifted = operand.unwrap_or_default().mul(10u32, "column number")?;
+                operand = Some(shifted.add(digit, "column number")?);
+            }
+
+            match (curr_op, operand) {
+                ('+', Some(operand)) => curr = curr.add(operand, "column sum")?,
+                ('*', Some(operand)) => curr = curr.mul(operand, "column product")?,
+                _ => {}
+            }
+        }
+        result.add(curr, "worksheet total")
+    }
+}
+
+/// A worksheet of `size` problems, each four numbers of up to three digits
+/// aligned within their column.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    const ROWS: usize = 4;
+    let mut rows = vec![Vec::new(); ROWS + 1];
+    for _ in 0..size.max(1) {
+        let numbers: Vec<String> = (0..ROWS)
+            .map(|_| rng.random_range(1..1000).to_string())
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let left = rng.random_bool(0.5);
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            row.push(if left {
+                format!("{number:<width$}")
+            } else {
+                format!("{number:>width$}")
+            });
+        }
+        let operator = if rng.random_bool(0.5) { "+" } else { "*" };
+        rows[ROWS].push(format!("{operator:<width$}"));
+    }
+    rows.iter().map(|row| row.join(" ") + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+
+    fn solve(part: u32, worksheet: &str) -> Result<Answer, AocError> {
+        let lines = Day6::parse(worksheet)?;
+        match part {
+            1 => Day6::part1(&lines, &Params::defaults(Day6::PARAMS, Part::One)),
+            _ => Day6::part2(&lines, &Params::defaults(Day6::PARAMS, Part::Two)),
+        }
+    }
+
+    /// Checked in debug builds, promoted in release ones; either way no panic.
+    fn overflows(answer: Result<Answer, AocError>, promoted: &str, step: &str) {
+        match answer {
+            Ok(answer) => assert_eq!(answer.to_string(), promoted),
+            Err(e) => assert!(e.to_string().starts_with(&format!("overflow in {step}")), "{e}"),
+        }
+    }
+
+    #[test]
+    fn long_column_numbers_overflow_safely() {
+        let worksheet = "9\n".repeat(21) + "+\n";
+        overflows(solve(2, &worksheet), &"9".repeat(21), "column number");
+    }
+
+    #[test]
+    fn differences_overflow_safely() {
+        let difference = solve(1, "-9223372036854775808\n1\n-\n");
+        overflows(difference, "-9223372036854775809", "column difference");
+    }
+
+    #[test]
+    fn division_by_zero_points_at_the_divisor() {
+        let e = solve(1, "5 8\n1 0\n/ /\n").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 3: division by zero (found `0`)");
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_core::parse;
+use aoc_core::{Answer, AocError, Grid, InputRng, Params, Pos, Solution};
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day7;
+
+/// Add `count` timelines to the beam in column `col`.
+fn merge(beams: &mut HashMap<usize, Answer>, col: usize, count: Answer) -> Result<(), AocError> {
+    let timelines = beams.entry(col).or_default();
+    *timelines = std::mem::take(timelines).add(count, "timelines")?;
+    Ok(())
+}
+
+fn total(beams: HashMap<usize, Answer>) -> Result<Answer, AocError> {
+    beams
+        .into_values()
+        .try_fold(Answer::ZERO, |acc, count| acc.add(count, "total timelines"))
+}
+
+fn recursive_part2(
+    grid: &Grid<char>,
+    row: usize,
+    incoming_beams: HashMap<usize, Answer>,
+) -> Result<Answer, AocError> {
+    if row == grid.height() {
+        return total(incoming_beams);
+    }
+    let mut next_beams = HashMap::new();
+    for (k, v) in incoming_beams {
+        let pos = Pos::new(row, k);
+        if grid[pos] == '^' {
+            let (left, right) = split(grid, pos)?;
+            merge(&mut next_beams, left, v.clone())?;
+            merge(&mut next_beams, right, v)?;
+        } else {
+            merge(&mut next_beams, k, v)?;
+        }
+    }
+    recursive_part2(grid, row + 1, next_beams)
+}
+
+/// Recursive alternative to part 2, one row of the manifold per call.
+pub fn part2_recursive(input: &str, _: &Params) -> Result<String, AocError> {
+    let grid = Day7::parse(input)?;
+    let start = grid.find(&'S').unwrap();
+    let timelines = recursive_part2(&grid, start.row + 1, HashMap::from([(start.col, Answer::ONE)]))?;
+    Ok(timelines.to_string())
+}
+
+/// Columns a splitter at `pos` sends its two beams to. A splitter on the
+/// edge would send one of them off the manifold.
+fn split(grid: &Grid<char>, pos: Pos) -> Result<(usize, usize), AocError> {
+    match (grid.offset(pos, 0, -1), grid.offset(pos, 0, 1)) {
+        (Some(left), Some(right)) => Ok((left.col, right.col)),
+        _ => Err(AocError::at(pos.row + 1, pos.col + 1, '^', "splitter on the edge of the manifold")),
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let cell = |c| "S^.".contains(c).then_some(c);
+        let grid = parse::grid(input, cell, "`S`, `^` or `.`")?;
+        if grid.row(0).iter().filter(|&&c| c == 'S').count() != 1 {
+            let text: String = grid.row(0).iter().collect();
+            return Err(AocError::at(1, 1, text, "expected exactly one `S` on the first line"));
+        }
+        for (i, line) in grid.rows().enumerate() {
+            let allowed = if i == 0 { "S." } else { "^." };
+            if let Some(col) = line.iter().position(|&c| !allowed.contains(c)) {
+                return Err(AocError::at(i + 1, col + 1, line[col], "unexpected cell in the manifold"));
+            }
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input, _: &Params) -> Result<usize, AocError> {
+        let mut split_count = 0;
+
+        // Parsing checked there is exactly one `S`, on the first line
+        let start = grid.find(&'S').unwrap();
+        let mut tachyon_beams = HashSet::from([start.col]);
+
+        for row in start.row + 1..grid.height() {
+            let mut next_beams = HashSet::new();
+            for beam in tachyon_beams {
+                let pos = Pos::new(row, beam);
+                if grid[pos] == '^' {
+                    let (left, right) = split(grid, pos)?;
+                    split_count += 1;
+                    next_beams.insert(left);
+                    next_beams.insert(right);
+                } else {
+                    next_beams.insert(beam);
+                }
+            }
+            tachyon_beams = next_beams;
+        }
+        Ok(split_count)
+    }
+
+    fn part2(grid: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        // Find position of 'S' on the first line
+        let start = grid.find(&'S').unwrap();
+        let mut beams = HashMap::from([(start.col, Answer::ONE)]);
+
+        // Iterate over lines
+        for row in start.row + 1..grid.height() {
+            let mut next_beams = HashMap::new();
+            for (idx, count) in beams {
+                let pos = Pos::new(row, idx);
+                if grid[pos] == '^' {
+                    let (left, right) = split(grid, pos)?;
+                    merge(&mut next_beams, left, count.clone())?;
+                    merge(&mut next_beams, right, count)?;
+                } else {
+                    merge(&mut next_beams, idx, count)?;
+                }
+            }
+            beams = next_beams;
+        }
+
+        total(beams)
+    }
+}
+
+/// A manifold `size` lines tall and wide enough for every beam, with
+/// splitters on every other line, never two side by side. Timelines grow
+/// exponentially with height.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let height = size.max(2);
+    let width = height + 3;
+    let mut input: String = (0..width).map(|c| if c == width / 2 { 'S' } else { '.' }).collect();
+    input.push('\n');
+    for row in 1..height {
+        let mut after_splitter = false;
+        for col in 0..width {
+            let edge = col == 0 || col + 1 == width;
+            let splitter = row % 2 == 0 && !edge && !after_splitter && rng.random_bool(0.3);
+            after_splitter = splitter;
+            input.push(if splitter { '^' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,190 @@
+use aoc_core::parse::{self, Line};
+use aoc_core::{Answer, AocError, InputRng, Param, Params, Solution};
+use rand::Rng;
+use std::collections::HashMap;
+use tracing::trace;
+
+pub struct Day8;
+
+// Union-Find (Disjoint Set Union) data structure
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+
+        if root_x == root_y {
+            return true;
+        }
+
+        // Union by size
+        if self.size[root_x] < self.size[root_y] {
+            self.parent[root_x] = root_y;
+            self.size[root_y] += self.size[root_x];
+        } else {
+            self.parent[root_y] = root_x;
+            self.size[root_x] += self.size[root_y];
+        }
+        true
+    }
+
+    fn get_component_sizes(&mut self) -> Vec<usize> {
+        let mut sizes = HashMap::new();
+        for i in 0..self.parent.len() {
+            let root = self.find(i);
+            *sizes.entry(root).or_insert(0) += 1;
+        }
+        sizes.values().copied().collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Position {
+    pub x: u64,
+    pub y: u64,
+    pub z: u64,
+}
+
+impl Position {
+    fn parse(line: Line) -> Result<Self, AocError> {
+        let coords: Vec<u64> = line.list(line.text.trim_start(), ',')?;
+        let [x, y, z] = coords[..] else {
+            return Err(line.error_line("expected three coordinates like `162,817,812`"));
+        };
+
+        Ok(Position { x, y, z })
+    }
+
+    fn distance(&self, other: &Position) -> f64 {
+        let dx = (self.x as f64) - (other.x as f64);
+        let dy = (self.y as f64) - (other.y as f64);
+        let dz = (self.z as f64) - (other.z as f64);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+/// All pairs of boxes, sorted by distance (smallest first).
+fn sorted_edges(positions: &[Position]) -> Vec<(f64, usize, usize)> {
+    let n = positions.len();
+    let mut edges: Vec<(f64, usize, usize)> = Vec::new();
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let distance = positions[i].distance(&positions[j]);
+            edges.push((distance, i, j));
+        }
+    }
+
+    edges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    edges
+}
+
+/// Sizes of the circuits after joining the `connect` closest pairs of
+/// boxes, largest first.
+pub fn circuit_sizes(positions: &[Position], connect: usize) -> Vec<usize> {
+    let edges = sorted_edges(positions);
+
+    // Initialize Union-Find
+    let mut uf = UnionFind::new(positions.len());
+
+    let mut connections_made = 0;
+
+    for (_distance, i, j) in edges {
+        if uf.union(i, j) {
+            connections_made += 1;
+            if connections_made == connect {
+                break;
+            }
+        }
+    }
+
+    // Get all component sizes
+    let mut sizes = uf.get_component_sizes();
+
+    sizes.sort_by(|a, b| b.cmp(a)); // Sort descending
+    sizes
+}
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    const PARAMS: &'static [Param] = &[Param::new(
+        "connect",
+        "1000",
+        "closest pairs of boxes joined before part 1 counts the circuits",
+    )];
+
+    type Input = Vec<Position>;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines(input).into_iter().map(Position::parse).collect()
+    }
+
+    fn part1(positions: &Self::Input, params: &Params) -> Result<usize, AocError> {
+        // Multiply the three largest
+        let sizes = circuit_sizes(positions, params.get("connect")?);
+        Ok(sizes.iter().take(3).product())
+    }
+
+    fn part2(positions: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let n = positions.len();
+        if n < 2 {
+            return Err(AocError::new("need at least two junction boxes to connect"));
+        }
+        let edges = sorted_edges(positions);
+
+        // Initialize Union-Find
+        let mut uf = UnionFind::new(n);
+
+        let mut last_connection = (0, 0); // Track the last connection made
+
+        // Connect until all nodes are in ONE circuit
+        for (distance, i, j) in edges {
+            if uf.union(i, j) {
+                last_connection = (i, j);
+
+                // Check if all nodes are now in one component
+                let num_circuits = uf.get_component_sizes().len();
+                trace!(i, j, distance, circuits = num_circuits, "connected boxes");
+
+                // Stop when we have only 1 circuit (all nodes connected)
+                if num_circuits == 1 {
+                    break;
+                }
+            }
+        }
+
+        let (box_i, box_j) = last_connection;
+        Answer::from(positions[box_i].x).mul(positions[box_j].x, "product of x coordinates")
+    }
+}
+
+/// `size` junction boxes scattered through a cube 100000 units wide.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    (0..size.max(2))
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.random_range(0..100_000));
+            format!("{x},{y},{z}\n")
+        })
+        .collect()
+}
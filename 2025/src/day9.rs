@@ -0,0 +1,133 @@
+use aoc_core::parse;
+use aoc_core::{Answer, AocError, InputRng, Params, Solution};
+use rand::Rng;
+use rand::seq::index;
+use tracing::debug;
+
+pub struct Day9;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Coord {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Coord {
+    fn area(&self, other: &Coord) -> Result<Answer, AocError> {
+        let side = |a: i64, b: i64| {
+            Answer::from(a.max(b)).sub(a.min(b), "rectangle side")?.add(1i64, "rectangle side")
+        };
+        side(self.x, other.x)?.mul(side(self.y, other.y)?, "rectangle area")
+    }
+}
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<Coord>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines(input)
+            .into_iter()
+            .map(|line| {
+                let (x, y) = line.split_once(line.text.trim_start(), ',', "a tile like `7,1`")?;
+                Ok(Coord {
+                    x: line.number(x)?,
+                    y: line.number(y)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(positions: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let n = positions.len();
+        let mut area = Answer::ZERO;
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let aux = positions[i].area(&positions[j])?;
+
+                if aux > area {
+                    area = aux;
+                }
+            }
+        }
+        Ok(area)
+    }
+
+    fn part2(corners: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let n = corners.len();
+
+        let mut edges = Vec::new();
+        let mut sizes = Vec::new();
+
+        for i in 0..n {
+            let mut edge = [corners[i], corners[(i + n - 1) % n]];
+            edge.sort();
+            edges.push(edge);
+            for j in (i + 1)..n {
+                let mut c1 = corners[i];
+                let mut c2 = corners[j];
+                if c2 < c1 {
+                    std::mem::swap(&mut c1, &mut c2);
+                }
+                let size = c1.area(&c2)?;
+                sizes.push((size, c1, c2));
+            }
+        }
+
+        edges.sort_by_key(|edge| (-edge[0].x, -edge[1].x));
+        sizes.sort_by(|(a, _, _), (b, _, _)| b.cmp(a));
+
+        debug!(rectangles = sizes.len(), largest = ?sizes.first(), "sorted candidate rectangles");
+
+        for (size, c1, c2) in &sizes {
+            let (x1, y1) = (c1.x, c1.y);
+            let (x2, y2) = (c2.x, c2.y);
+            let (y1, y2) = if y1 < y2 { (y1, y2) } else { (y2, y1) };
+
+            let mut found = false;
+            for edge in &edges {
+                let (x3, y3) = (edge[0].x, edge[0].y);
+                let (x4, y4) = (edge[1].x, edge[1].y);
+                if x4 > x1 && x3 < x2 && y4 > y1 && y3 < y2 {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                // Found max size without infinite area
+                return Ok(size.clone());
+            }
+        }
+        Ok(Answer::ZERO)
+    }
+}
+
+/// A rectilinear polygon of about `size` red tiles, shaped like a histogram:
+/// up the left side, along a staircase of column tops, and back down.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    const SPAN: usize = 100_000;
+    let columns = (size.saturating_sub(2) / 2).clamp(1, SPAN / 2);
+    let mut xs: Vec<usize> = index::sample(rng, SPAN, columns + 1).into_vec();
+    xs.sort();
+
+    let mut tiles = Vec::new();
+    let mut height = 0;
+    for (i, window) in xs.windows(2).enumerate() {
+        let mut next = rng.random_range(1..SPAN);
+        while next == height {
+            next = rng.random_range(1..SPAN);
+        }
+        if i == 0 {
+            tiles.push((window[0], 0));
+        }
+        tiles.push((window[0], next));
+        tiles.push((window[1], next));
+        height = next;
+    }
+    tiles.push((xs[columns], 0));
+    tiles.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
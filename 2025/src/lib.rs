@@ -0,0 +1,61 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+
+use aoc_core::{Day, Generator, Part, Tracer, Variant, Year};
+
+/// Every solved day, in puzzle order.
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+];
+
+/// Alternative implementations, checked against the main ones.
+pub const VARIANTS: &[Variant] = &[
+    Variant::new(1, Part::Two, "click_by_click", day1::part2_click_by_click),
+    Variant::new(2, Part::Two, "log10", day2::part2_log10),
+    Variant::new(7, Part::Two, "recursive", day7::part2_recursive),
+    Variant::new(11, Part::One, "segment_count", day11::part1_segment_count),
+    Variant::new(11, Part::Two, "dfs_count", day11::part2_dfs_count),
+    Variant::new(11, Part::Two, "all_paths", day11::part2_all_paths),
+];
+
+/// Input generators, with what their size counts.
+pub const GENERATORS: &[Generator] = &[
+    Generator::new(1, "rotations", day1::generate),
+    Generator::new(2, "ID ranges", day2::generate),
+    Generator::new(3, "battery banks", day3::generate),
+    Generator::new(4, "rows and columns", day4::generate),
+    Generator::new(5, "fresh ranges and ingredients", day5::generate),
+    Generator::new(6, "problems", day6::generate),
+    Generator::new(7, "lines", day7::generate),
+    Generator::new(8, "junction boxes", day8::generate),
+    Generator::new(9, "red tiles", day9::generate),
+    Generator::new(10, "machines", day10::generate),
+    Generator::new(11, "devices", day11::generate),
+];
+
+/// Step-by-step tracers, for diffing a run against someone else's.
+pub const TRACERS: &[Tracer] = &[Tracer::new(1, day1::trace)];
+
+pub const YEAR: Year = Year::new(2025, env!("CARGO_MANIFEST_DIR"), DAYS)
+    .with_variants(VARIANTS)
+    .with_generators(GENERATORS)
+    .with_tracers(TRACERS);
@@ -0,0 +1,24 @@
+use aoc2025::YEAR;
+use aoc_core::{Part, answers};
+use std::fs;
+
+fn check(day: u32, input: &str, part: Part) {
+    let dir = YEAR.input_dir(day);
+    let known = answers::load(&dir).unwrap();
+    let expected = answers::find(&known, input, part).unwrap_or_else(|| {
+        panic!(
+            "no answer recorded for day {day} part {part} on {input} in {}",
+            dir.join(answers::FILE_NAME).display()
+        )
+    });
+
+    let text = fs::read_to_string(dir.join(input)).unwrap();
+    let solution = YEAR.day(day).unwrap();
+    assert_eq!(
+        solution.solve_with(part, &text, &expected.params).unwrap(),
+        expected.answer,
+        "day {day} part {part} on {input}"
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
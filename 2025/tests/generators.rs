@@ -0,0 +1,60 @@
+//! Generated inputs must be valid puzzle inputs: both parts solve them, to
+//! the same answer every time, and a seed always gives back the same input.
+
+use aoc2025::YEAR;
+use aoc_core::Part;
+
+#[test]
+fn generated_inputs_solve() {
+    for generator in YEAR.generators {
+        let day = YEAR.day(generator.day).unwrap();
+        for seed in 0..5 {
+            let input = generator.generate(seed, 20);
+            for part in Part::ALL {
+                let answer = day.solve(part, &input);
+                let answer = answer.unwrap_or_else(|e| {
+                    panic!("seed {seed} of day {}: {e}\ninput:\n{input}", day.day)
+                });
+                let again = day.solve(part, &input).unwrap();
+                assert_eq!(answer, again, "seed {seed} of day {} part {part}", day.day);
+            }
+        }
+    }
+}
+
+#[test]
+fn seeds_are_reproducible() {
+    for generator in YEAR.generators {
+        assert_eq!(generator.generate(42, 10), generator.generate(42, 10));
+        assert_ne!(generator.generate(1, 10), generator.generate(2, 10), "day {}", generator.day);
+    }
+}
+
+#[test]
+fn day11_outgrows_three_letter_names() {
+    let generator = YEAR.generator(11).unwrap();
+    let input = generator.generate(0, 20_000);
+    let devices: Vec<&str> = input.lines().filter_map(|line| line.split(':').next()).collect();
+    assert_eq!(devices.len(), 19_999, "every device but `out` has a line");
+    let unique: std::collections::HashSet<&str> = devices.iter().copied().collect();
+    assert_eq!(unique.len(), devices.len());
+}
+
+#[test]
+fn day11_always_has_part2_paths() {
+    let generator = YEAR.generator(11).unwrap();
+    let day = YEAR.day(11).unwrap();
+    for seed in 0..20 {
+        let paths = day.solve(Part::Two, &generator.generate(seed, 50)).unwrap();
+        assert_ne!(paths, "0", "seed {seed}");
+    }
+}
+
+#[test]
+fn day11_solves_long_chains_of_devices() {
+    let input = YEAR.generator(11).unwrap().generate(0, 200_000);
+    // So many paths overflow in checked mode, which is an answer too
+    if let Err(e) = YEAR.day(11).unwrap().solve(Part::Two, &input) {
+        assert!(e.to_string().contains("overflow in path count"), "{e}");
+    }
+}
@@ -0,0 +1,122 @@
+//! Every registered variant of a part must agree with the main solution on
+//! random puzzle inputs. On a disagreement proptest shrinks the input
+//! before reporting it, so the failure shows the smallest one it found.
+
+use aoc2025::YEAR;
+use aoc_core::{Part, variants};
+use proptest::prelude::*;
+
+/// Parts with a generator below; every registered variant must be one of them.
+const COVERED: &[(u32, Part)] = &[
+    (1, Part::Two),
+    (2, Part::Two),
+    (7, Part::Two),
+    (11, Part::One),
+    (11, Part::Two),
+];
+
+fn agree(day: u32, part: Part, input: &str) -> Result<(), TestCaseError> {
+    let solution = YEAR.day(day).unwrap();
+    variants::compare(solution, part, YEAR.variants, input)
+        .map_err(|e| TestCaseError::fail(format!("{e}\ninput:\n{input}")))
+}
+
+/// Rotations of up to several turns of the dial, often whole turns.
+fn day1_input() -> impl Strategy<Value = String> {
+    let clicks = prop_oneof![1i32..1000, (1i32..5).prop_map(|turns| turns * 100)];
+    let rotation = (prop::bool::ANY, clicks).prop_map(|(left, clicks)| format!("{}{clicks}", if left { 'L' } else { 'R' }));
+    prop::collection::vec(rotation, 1..30).prop_map(|rotations| rotations.join("\n"))
+}
+
+/// Ranges of IDs with 1 to 10 digits, as far as the log10 variant goes.
+fn day2_input() -> impl Strategy<Value = String> {
+    let range = (1u32..=10, any::<u64>(), 0u64..300).prop_map(|(digits, offset, len)| {
+        let low = 10u64.pow(digits - 1);
+        let high = 10u64.pow(digits) - 1;
+        let start = low + offset % (high - low + 1);
+        format!("{start}-{}", (start + len).min(high))
+    });
+    prop::collection::vec(range, 1..6).prop_map(|ranges| ranges.join(","))
+}
+
+/// Manifolds with no splitters on the edges, which no variant accepts.
+fn day7_input() -> impl Strategy<Value = String> {
+    (3usize..12)
+        .prop_flat_map(|width| {
+            let row = prop::collection::vec(prop::bool::weighted(0.3), width - 2);
+            (0..width, prop::collection::vec(row, 1..12))
+        })
+        .prop_map(|(start, rows)| {
+            let width = rows[0].len() + 2;
+            let first: String = (0..width).map(|c| if c == start { 'S' } else { '.' }).collect();
+            let mut lines = vec![first];
+            for row in rows {
+                let inner: String = row.iter().map(|&split| if split { '^' } else { '.' }).collect();
+                lines.push(format!(".{inner}."));
+            }
+            lines.join("\n")
+        })
+}
+
+/// Device graphs without cycles: devices only feed devices later in a
+/// shuffled order, and `out` comes last.
+fn day11_input() -> impl Strategy<Value = String> {
+    (0usize..6)
+        .prop_flat_map(|extra| {
+            let mut names: Vec<String> = ["svr", "you", "dac", "fft"].map(String::from).to_vec();
+            names.extend((0..extra).map(|i| format!("d{i}")));
+            let n = names.len() + 1;
+            (Just(names).prop_shuffle(), prop::collection::vec(prop::bool::weighted(0.4), n * n))
+        })
+        .prop_map(|(mut names, edges)| {
+            names.push("out".to_string());
+            let n = names.len();
+            let lines: Vec<String> = (0..n - 1)
+                .map(|i| {
+                    let targets: Vec<&str> = (i + 1..n)
+                        .filter(|&j| edges[i * n + j])
+                        .map(|j| names[j].as_str())
+                        .collect();
+                    format!("{}: {}", names[i], targets.join(" "))
+                })
+                .collect();
+            lines.join("\n")
+        })
+}
+
+proptest! {
+    #[test]
+    fn day01_part2_variants_agree(input in day1_input()) {
+        agree(1, Part::Two, &input)?;
+    }
+
+    #[test]
+    fn day02_part2_variants_agree(input in day2_input()) {
+        agree(2, Part::Two, &input)?;
+    }
+
+    #[test]
+    fn day07_part2_variants_agree(input in day7_input()) {
+        agree(7, Part::Two, &input)?;
+    }
+
+    #[test]
+    fn day11_part1_variants_agree(input in day11_input()) {
+        agree(11, Part::One, &input)?;
+    }
+
+    #[test]
+    fn day11_part2_variants_agree(input in day11_input()) {
+        agree(11, Part::Two, &input)?;
+    }
+}
+
+#[test]
+fn every_variant_has_a_generator() {
+    for variant in YEAR.variants {
+        assert!(
+            COVERED.contains(&(variant.day, variant.part)),
+            "no generator for {variant:?}"
+        );
+    }
+}
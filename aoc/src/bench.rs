@@ -0,0 +1,112 @@
+use crate::run;
+use crate::years::{self, Years};
+use clap::Args;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Year to benchmark, or `all`; the most recent year when omitted
+    #[arg(long)]
+    year: Option<Years>,
+    /// Day to benchmark, every day of the year when omitted
+    #[arg(long)]
+    day: Option<u32>,
+    /// Number of timed runs per day
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    /// Where to write the JSON report
+    #[arg(long, default_value = "bench_output.txt")]
+    output: PathBuf,
+}
+
+#[derive(Serialize)]
+struct Report {
+    runs: usize,
+    results: Vec<PhaseStats>,
+}
+
+/// Summary of one phase of one day over all runs, in nanoseconds.
+#[derive(Serialize)]
+struct PhaseStats {
+    year: u32,
+    day: u32,
+    phase: &'static str,
+    min_ns: u128,
+    median_ns: u128,
+    p95_ns: u128,
+}
+
+impl PhaseStats {
+    fn new(year: u32, day: u32, phase: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let p95 = (samples.len() * 95).div_ceil(100).max(1) - 1;
+        PhaseStats {
+            year,
+            day,
+            phase,
+            min_ns: samples[0].as_nanos(),
+            median_ns: samples[samples.len() / 2].as_nanos(),
+            p95_ns: samples[p95].as_nanos(),
+        }
+    }
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+    if args.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+    let mut days = Vec::new();
+    for year in years::select(args.year)? {
+        match args.day {
+            Some(day) if args.year == Some(Years::All) => days.extend(year.day(day).map(|d| (year, d))),
+            Some(day) => days.push((year, years::find(year, day)?)),
+            None => days.extend(year.days.iter().map(|d| (year, d))),
+        }
+    }
+
+    let mut results = Vec::new();
+    println!(
+        "{:>4} {:>3} {:<6} {:>12} {:>12} {:>12}",
+        "year", "day", "phase", "min", "median", "p95"
+    );
+    for (year, day) in days {
+        let path = year.input_dir(day.day).join("input.txt");
+        let input = run::read_input(&path)?;
+        let timings = (0..args.runs)
+            .map(|_| day.time(&input))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+
+        let phases = [
+            ("parse", timings.iter().map(|t| t.parse).collect()),
+            ("part1", timings.iter().map(|t| t.part1).collect()),
+            ("part2", timings.iter().map(|t| t.part2).collect()),
+        ];
+        for (phase, samples) in phases {
+            let stats = PhaseStats::new(year.year, day.day, phase, samples);
+            println!(
+                "{:>4} {:>3} {:<6} {:>12?} {:>12?} {:>12?}",
+                stats.year,
+                stats.day,
+                stats.phase,
+                Duration::from_nanos(stats.min_ns as u64),
+                Duration::from_nanos(stats.median_ns as u64),
+                Duration::from_nanos(stats.p95_ns as u64),
+            );
+            results.push(stats);
+        }
+    }
+
+    let report = Report {
+        runs: args.runs,
+        results,
+    };
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    fs::write(&args.output, json + "\n")
+        .map_err(|e| format!("failed to write {}: {e}", args.output.display()))?;
+    println!("wrote {}", args.output.display());
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::run;
+use crate::summary::{self, Check};
+use crate::years;
+use aoc_core::answers;
+use aoc_core::{Day, Part};
+use clap::Args;
+use rayon::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// Year of the day; the most recent year when omitted
+    #[arg(long)]
+    year: Option<u32>,
+    /// Day whose solution to check
+    #[arg(long)]
+    day: u32,
+    /// Directory of puzzle inputs, each checked against the
+    /// `NAME.answers` sidecar next to it when there is one
+    #[arg(long)]
+    inputs: PathBuf,
+    /// Part to check, both when omitted
+    #[arg(long)]
+    part: Option<Part>,
+}
+
+/// Solve every input in a directory and print a matrix of inputs by parts,
+/// each cell checked against the input's sidecar answers. Catches solutions
+/// that only work on the input they were written for.
+pub fn check(args: CheckArgs) -> Result<(), String> {
+    let year = years::year(args.year)?;
+    let day = years::find(year, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let inputs = list_inputs(&args.inputs)?;
+    if inputs.is_empty() {
+        return Err(format!("no inputs in {}", args.inputs.display()));
+    }
+
+    let jobs: Vec<(&Path, Part)> = inputs
+        .iter()
+        .flat_map(|input| parts.iter().map(move |&part| (input.as_path(), part)))
+        .collect();
+    let checks: Vec<(String, Check)> = jobs
+        .into_par_iter()
+        .map(|(input, part)| solve(day, input, part))
+        .collect();
+
+    let mut header = vec!["input".to_string()];
+    header.extend(parts.iter().map(|part| format!("part {part}")));
+    let header: Vec<&str> = header.iter().map(String::as_str).collect();
+    let cells: Vec<Vec<String>> = inputs
+        .iter()
+        .zip(checks.chunks(parts.len()))
+        .map(|(input, row)| {
+            let name = input.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let mut cells = vec![name];
+            cells.extend(row.iter().map(|(answer, check)| cell(answer, check)));
+            cells
+        })
+        .collect();
+    summary::print_aligned(&header, &cells, &vec![false; header.len()]);
+
+    let bad = checks.iter().filter(|(_, check)| check.failed()).count();
+    match bad {
+        0 => Ok(()),
+        _ => Err(format!("{bad} of {} answers failed or did not match", checks.len())),
+    }
+}
+
+/// Every puzzle input in `dir`, by name. Sidecar answers and hidden files
+/// are not inputs.
+fn list_inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("failed to read {}: {e}", dir.display()))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| format!("failed to read {}: {e}", dir.display()))?.path();
+        let hidden = path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        let sidecar = path.extension().is_some_and(|ext| ext == answers::SIDECAR_EXTENSION);
+        if path.is_file() && !hidden && !sidecar {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn solve(day: &Day, input: &Path, part: Part) -> (String, Check) {
+    let name = input.file_name().unwrap_or_default().to_string_lossy();
+    let known = answers::load_sidecar(input).map_err(|e| e.to_string());
+    let recorded = known
+        .as_ref()
+        .ok()
+        .and_then(|known| answers::find(known, &name, part));
+    let params = recorded.map(|k| k.params.as_slice()).unwrap_or_default();
+
+    let answer = run::read_input(input)
+        .and_then(|text| day.solve_with(part, &text, params).map_err(|e| e.to_string()));
+    let expected = known
+        .as_ref()
+        .map(|_| recorded.map(|k| k.answer.clone()))
+        .map_err(Clone::clone);
+    Check::of(answer, expected)
+}
+
+fn cell(answer: &str, check: &Check) -> String {
+    match check {
+        Check::Correct => format!("ok {answer}"),
+        Check::Wrong(expected) => format!("wrong {answer}, expected {expected}"),
+        Check::Unknown => format!("unchecked {answer}"),
+        Check::Failed => format!("error: {answer}"),
+    }
+}
@@ -0,0 +1,44 @@
+use crate::years;
+use clap::Args;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct GenArgs {
+    /// The most recent year when omitted
+    #[arg(long)]
+    year: Option<u32>,
+    #[arg(long)]
+    day: u32,
+    /// How big an input to make, in the unit of the day (e.g. junction boxes)
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+    /// Seed for the generator; the same seed gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Where to write the input, stdout when omitted
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+pub fn generate(args: GenArgs) -> Result<(), String> {
+    let year = years::year(args.year)?;
+    let generator = year
+        .generator(args.day)
+        .ok_or_else(|| format!("no input generator for {} day {}", year.year, args.day))?;
+    let input = generator.generate(args.seed, args.size);
+
+    match args.output {
+        Some(path) => {
+            fs::write(&path, input).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+            eprintln!(
+                "wrote {} {} to {}",
+                args.size,
+                generator.unit,
+                path.display()
+            );
+        }
+        None => print!("{input}"),
+    }
+    Ok(())
+}
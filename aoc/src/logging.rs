@@ -0,0 +1,53 @@
+use clap::Args;
+use std::env;
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+
+/// Where the solutions' trace events go, shared by every command.
+#[derive(Args)]
+pub struct LogArgs {
+    /// Show more of what the solutions do: -v for answers, -vv for
+    /// debug events, -vvv for every step
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Event filter like `aoc2025::day8=trace`, overriding -v and `RUST_LOG`
+    #[arg(long, global = true)]
+    log: Option<String>,
+    /// Emit events as JSON lines
+    #[arg(long, global = true)]
+    log_json: bool,
+    /// Write events to this file instead of stderr
+    #[arg(long, global = true)]
+    log_file: Option<PathBuf>,
+}
+
+pub fn init(args: &LogArgs) -> Result<(), String> {
+    let directives = match (&args.log, env::var("RUST_LOG")) {
+        (Some(filter), _) => filter.clone(),
+        (None, Ok(filter)) if args.verbose == 0 && !filter.is_empty() => filter,
+        _ => ["warn", "info", "debug", "trace"][usize::from(args.verbose).min(3)].to_string(),
+    };
+    let filter = EnvFilter::try_new(&directives).map_err(|e| format!("invalid log filter `{directives}`: {e}"))?;
+
+    let writer = match &args.log_file {
+        Some(path) => {
+            let file = File::create(path).map_err(|e| format!("failed to create {}: {e}", path.display()))?;
+            BoxMakeWriter::new(Mutex::new(file))
+        }
+        None => BoxMakeWriter::new(io::stderr),
+    };
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer)
+        .with_ansi(args.log_file.is_none());
+    if args.log_json {
+        builder.json().init();
+    } else {
+        builder.init();
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+mod bench;
+mod check;
+mod generate;
+mod logging;
+mod new;
+mod run;
+mod serve;
+mod submit;
+mod summary;
+mod trace;
+mod watch;
+mod years;
+
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: logging::LogArgs,
+    /// Fail on integer overflow instead of moving on to big integers;
+    /// always on in debug builds
+    #[arg(long, global = true)]
+    checked: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, printing the answer of each requested part
+    Run(run::RunArgs),
+    /// Solve one day on every input in a directory, checked against each
+    /// input's sidecar answers
+    Check(check::CheckArgs),
+    /// Record an answer's verdict, refusing answers already rejected
+    Submit(submit::SubmitArgs),
+    /// Time parse, part 1 and part 2 of each day over repeated runs
+    Bench(bench::BenchArgs),
+    /// Write a random puzzle input of a given size
+    Gen(generate::GenArgs),
+    /// Scaffold a new day: module, empty inputs and answers file
+    New(new::NewArgs),
+    /// Answer solve requests over HTTP: POST the puzzle input to
+    /// /{year}/day/{n}/part/{p}
+    Serve(serve::ServeArgs),
+    /// Re-run a day whenever its module or input changes
+    Watch(watch::WatchArgs),
+    /// Write each step of a day's solution as CSV or JSON lines
+    Trace(trace::TraceArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(message) = logging::init(&cli.log) {
+        eprintln!("error: {message}");
+        return ExitCode::FAILURE;
+    }
+    if cli.checked {
+        aoc_core::answer::set_checked(true);
+    }
+    let result = match cli.command {
+        Command::Run(args) => run::run(args),
+        Command::Check(args) => check::check(args),
+        Command::Submit(args) => submit::submit(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::New(args) => new::new(args),
+        Command::Gen(args) => generate::generate(args),
+        Command::Serve(args) => serve::serve(args),
+        Command::Watch(args) => watch::watch(args),
+        Command::Trace(args) => trace::trace(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::years;
+use aoc_core::answers;
+use clap::Args;
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// The most recent year when omitted
+    #[arg(long)]
+    year: Option<u32>,
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+}
+
+pub fn new(args: NewArgs) -> Result<(), String> {
+    let year = years::year(args.year)?;
+    let root = year.root();
+    if year.day(args.day).is_some() {
+        return Err(format!("{} day {} already exists", year.year, args.day));
+    }
+    let day = args.day;
+    let module = root.join("src").join(format!("day{day}.rs"));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    fs::write(&module, TEMPLATE.replace("{day}", &day.to_string()))
+        .map_err(|e| format!("failed to write {}: {e}", module.display()))?;
+    println!("created {}", module.display());
+
+    let dir = year.input_dir(day);
+    fs::create_dir_all(&dir).map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+    let header = "# input part answer\n";
+    for (name, contents) in [("input.txt", ""), ("input_test.txt", ""), (answers::FILE_NAME, header)] {
+        let path = dir.join(name);
+        if !path.exists() {
+            fs::write(&path, contents).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+            println!("created {}", path.display());
+        }
+    }
+
+    register(&root.join("src").join("lib.rs"), day)?;
+    println!("registered day {day}; rebuild to run it");
+    Ok(())
+}
+
+/// Add `pub mod dayN;` and its entry in `DAYS`, each in day order.
+fn register(lib: &Path, day: u32) -> Result<(), String> {
+    let text = fs::read_to_string(lib).map_err(|e| format!("failed to read {}: {e}", lib.display()))?;
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+
+    insert_sorted(&mut lines, day, format!("pub mod day{day};"), |line| {
+        line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok()
+    })
+    .ok_or_else(|| format!("no `pub mod dayN;` lines in {}", lib.display()))?;
+    insert_sorted(&mut lines, day, format!("    Day::of::<day{day}::Day{day}>(),"), |line| {
+        line.trim().strip_prefix("Day::of::<day")?.split_once("::")?.0.parse().ok()
+    })
+    .ok_or_else(|| format!("no `DAYS` entries in {}", lib.display()))?;
+
+    fs::write(lib, lines.join("\n") + "\n").map_err(|e| format!("failed to write {}: {e}", lib.display()))
+}
+
+/// Insert `new` among the lines `day_of` recognises, before the first later
+/// day or else after the last one. `None` when no line is recognised.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    day: u32,
+    new: String,
+    day_of: impl Fn(&str) -> Option<u32>,
+) -> Option<()> {
+    let matching: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    let &(last, _) = matching.last()?;
+    let at = matching
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, new);
+    Some(())
+}
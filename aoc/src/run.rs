@@ -0,0 +1,111 @@
+use crate::summary;
+use crate::years::{self, Years};
+use aoc_core::answers;
+use aoc_core::{Override, Part};
+use clap::Args;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Year to run, or `all`; the most recent year when omitted
+    #[arg(long)]
+    year: Option<Years>,
+    /// Day to run, every day of the year when omitted
+    #[arg(long)]
+    day: Option<u32>,
+    /// Part to solve, both when omitted
+    #[arg(long)]
+    part: Option<Part>,
+    /// Puzzle input, `-` for stdin; defaults to the day's `input.txt`
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Set one of the day's parameters, e.g. `--param connect=10`; repeat
+    /// for more. Applied after any recorded with the input's answer
+    #[arg(long = "param", value_name = "NAME=VALUE", requires = "day")]
+    params: Vec<Override>,
+    /// Solve every day in parallel and print a table checked against the
+    /// recorded answers
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+    /// Print the `--all` table as Markdown
+    #[arg(long, requires = "all")]
+    markdown: bool,
+}
+
+pub fn run(args: RunArgs) -> Result<(), String> {
+    let years = years::select(args.year)?;
+    if args.input.is_some() && years.len() > 1 {
+        return Err("--input needs a single --year".to_string());
+    }
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    if args.all {
+        return summary::run_all(&years, &parts, args.markdown);
+    }
+
+    for year in years {
+        let days = match args.day {
+            Some(day) if args.year == Some(Years::All) => year.day(day).into_iter().collect(),
+            Some(day) => vec![years::find(year, day)?],
+            None => year.days.iter().collect::<Vec<_>>(),
+        };
+        for day in days {
+            let path = match &args.input {
+                Some(path) => path.clone(),
+                None => year.input_dir(day.day).join("input.txt"),
+            };
+            let input = read_input(&path)?;
+            for &part in &parts {
+                let mut params = recorded_params(&year.input_dir(day.day), &path, part)?;
+                params.extend(args.params.iter().cloned());
+                let answer = day
+                    .solve_with(part, &input, &params)
+                    .map_err(|e| format!("{}: {e}", source_name(&path)))?;
+                println!("{} day {} part {}: {}", year.year, day.day, part, answer);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parameters the answers registry records for `part` of `path`, when it
+/// is one of the inputs kept in the day's directory `dir`.
+fn recorded_params(dir: &Path, path: &Path, part: Part) -> Result<Vec<Override>, String> {
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name().and_then(|n| n.to_str()))
+    else {
+        return Ok(Vec::new());
+    };
+    if parent.canonicalize().ok() != dir.canonicalize().ok() {
+        return Ok(Vec::new());
+    }
+    let known = answers::load(dir).map_err(|e| e.to_string())?;
+    Ok(answers::find(&known, name, part)
+        .map(|k| k.params.clone())
+        .unwrap_or_default())
+}
+
+/// Read the puzzle input from `path`, or from stdin when it is `-`.
+pub fn read_input(path: &Path) -> Result<String, String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("failed to read stdin: {e}"))?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))
+    }
+}
+
+/// How to refer to an input path in messages.
+pub fn source_name(path: &Path) -> String {
+    if path.as_os_str() == "-" {
+        "stdin".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
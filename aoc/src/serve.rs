@@ -0,0 +1,227 @@
+use crate::years;
+use aoc_core::{AocError, Day, Override, Params, Part};
+use clap::Args;
+use serde::Serialize;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Largest puzzle input accepted, far above any real one.
+const MAX_INPUT: u64 = 16 << 20;
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Address to listen on; keep it on localhost unless the network is
+    /// trusted, as anyone who can reach it can keep the solvers busy
+    #[arg(long, default_value = "127.0.0.1:8025")]
+    addr: SocketAddr,
+}
+
+/// Body of every reply to a solve request, whether it was solved or not.
+#[derive(Serialize)]
+struct Solved {
+    year: u32,
+    day: u32,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<Problem>,
+    time_ns: u128,
+}
+
+/// Body of a reply to a request that never reached a solver.
+#[derive(Serialize)]
+struct Rejected {
+    error: Problem,
+}
+
+/// What went wrong, with where in the input when it is about the input.
+#[derive(Serialize)]
+struct Problem {
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl Problem {
+    fn new(message: impl Into<String>) -> Self {
+        Problem {
+            message: message.into(),
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+}
+
+impl From<AocError> for Problem {
+    fn from(e: AocError) -> Self {
+        let AocError { location, message, .. } = e;
+        Problem {
+            message,
+            line: location.as_ref().map(|l| l.line),
+            column: location.as_ref().map(|l| l.column),
+            text: location.map(|l| l.text),
+        }
+    }
+}
+
+/// A request that made it past routing.
+struct Route {
+    year: u32,
+    day: &'static Day,
+    part: Part,
+    params: Vec<Override>,
+}
+
+/// Answer `POST /{year}/day/{n}/part/{p}` with the puzzle input as the
+/// body, solving each request on the thread pool. Query parameters such as
+/// `?connect=10` override the day's parameters.
+pub fn serve(args: ServeArgs) -> Result<(), String> {
+    let server = Server::http(args.addr).map_err(|e| format!("failed to listen on {}: {e}", args.addr))?;
+    eprintln!("listening on http://{}", args.addr);
+    for request in server.incoming_requests() {
+        rayon::spawn(move || handle(request));
+    }
+    Ok(())
+}
+
+fn handle(mut request: Request) {
+    let (status, body) = match route(request.method(), request.url()) {
+        Err((status, message)) => (status, json(&Rejected { error: Problem::new(message) })),
+        Ok(route) => {
+            // One byte past the limit tells a body that is too big from one
+            // that just fits
+            let mut input = String::new();
+            let read = request.as_reader().take(MAX_INPUT + 1).read_to_string(&mut input);
+            match read {
+                Err(e) => (400, json(&Rejected { error: Problem::new(format!("unreadable input: {e}")) })),
+                Ok(n) if n as u64 > MAX_INPUT => {
+                    let message = format!("the input is over the limit of {} MiB", MAX_INPUT >> 20);
+                    (413, json(&Rejected { error: Problem::new(message) }))
+                }
+                Ok(_) => solve_caught(&route, &input),
+            }
+        }
+    };
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(e) = request.respond(response) {
+        eprintln!("warning: failed to reply: {e}");
+    }
+}
+
+/// Pick the day and part out of a path like `/2025/day/8/part/1?connect=10`.
+/// Failures come with the HTTP status to reply with.
+fn route(method: &Method, url: &str) -> Result<Route, (u16, String)> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let [year, "day", day, "part", part] = segments[..] else {
+        return Err((404, format!("no route for {path}, expected /{{year}}/day/{{n}}/part/{{p}}")));
+    };
+    if *method != Method::Post {
+        return Err((405, format!("{method} is not supported, POST the puzzle input")));
+    }
+
+    let year: u32 = year.parse().map_err(|_| (404, format!("invalid year `{year}`")))?;
+    let day: u32 = day.parse().map_err(|_| (404, format!("invalid day `{day}`")))?;
+    let part: Part = part.parse().map_err(|e| (404, e))?;
+    let year = years::year(Some(year)).map_err(|e| (404, e))?;
+    let day = years::find(year, day).map_err(|e| (404, e))?;
+    let params: Vec<Override> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(query_param)
+        .collect::<Result<_, _>>()
+        .map_err(|e| (400, e))?;
+    Params::new(day.params, part, &params).map_err(|e| (400, e.message))?;
+    Ok(Route {
+        year: year.year,
+        day,
+        part,
+        params,
+    })
+}
+
+/// One `name=value` of a query string, with both sides percent-decoded so
+/// that `?dials=A%3A10` sets `dials` to `A:10`.
+fn query_param(param: &str) -> Result<Override, String> {
+    let Some((name, value)) = param.split_once('=').filter(|(name, _)| !name.is_empty()) else {
+        return Err(format!("expected a parameter like `name=value`, got `{param}`"));
+    };
+    Ok(Override {
+        name: percent_decode(name)?,
+        value: percent_decode(value)?,
+    })
+}
+
+/// Undo the escaping of a query string: `+` is a space and `%XX` a byte.
+fn percent_decode(text: &str) -> Result<String, String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let digit = |i: usize| rest.get(i).and_then(|&b| char::from(b).to_digit(16));
+                let (Some(high), Some(low)) = (digit(0), digit(1)) else {
+                    return Err(format!("invalid escape in the query `{text}`"));
+                };
+                bytes.push((high * 16 + low) as u8);
+                rest = &rest[2..];
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| format!("the query `{text}` is not valid UTF-8"))
+}
+
+/// [`solve`], replying 500 when the solver panics instead of letting the
+/// panic abort the thread pool and the server with it.
+fn solve_caught(route: &Route, input: &str) -> (u16, String) {
+    panic::catch_unwind(AssertUnwindSafe(|| solve(route, input))).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        let error = Problem::new(format!("the solver panicked: {message}"));
+        (500, json(&Rejected { error }))
+    })
+}
+
+fn solve(route: &Route, input: &str) -> (u16, String) {
+    let start = Instant::now();
+    let answer = route.day.solve_with(route.part, input, &route.params);
+    let time_ns = start.elapsed().as_nanos();
+    let (status, answer, error) = match answer {
+        Ok(answer) => (200, Some(answer), None),
+        Err(e) => (422, None, Some(Problem::from(e))),
+    };
+    let solved = Solved {
+        year: route.year,
+        day: route.day.day,
+        part: match route.part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        answer,
+        error,
+        time_ns,
+    };
+    (status, json(&solved))
+}
+
+fn json(body: &impl Serialize) -> String {
+    serde_json::to_string(body).expect("replies always serialize")
+}
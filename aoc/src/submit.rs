@@ -0,0 +1,97 @@
+use crate::{run, years};
+use aoc_core::Part;
+use aoc_core::answers::{self, Known};
+use aoc_core::submissions::{Check, Log, Submission, Verdict};
+use clap::Args;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// The most recent year when omitted
+    #[arg(long)]
+    year: Option<u32>,
+    #[arg(long)]
+    day: u32,
+    #[arg(long)]
+    part: Part,
+    /// Answer to submit; solved from the day's `input.txt` when omitted
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+/// Something that can tell whether an answer is right, like the puzzle server.
+pub trait Judge {
+    fn judge(&self, part: Part, answer: &str) -> Result<Verdict, String>;
+}
+
+/// Offline stand-in for the puzzle server, judging against the answers
+/// registry entry for the day's `input.txt`.
+pub struct LocalJudge {
+    known: Vec<Known>,
+}
+
+impl LocalJudge {
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let known = answers::load(dir).map_err(|e| e.to_string())?;
+        Ok(LocalJudge { known })
+    }
+}
+
+impl Judge for LocalJudge {
+    fn judge(&self, part: Part, answer: &str) -> Result<Verdict, String> {
+        let expected = answers::lookup(&self.known, "input.txt", part)
+            .ok_or_else(|| format!("no recorded answer for part {part} of input.txt to judge against"))?;
+        if answer == expected {
+            return Ok(Verdict::Correct);
+        }
+        Ok(match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => Verdict::TooHigh,
+            (Ok(_), Ok(_)) => Verdict::TooLow,
+            _ => Verdict::Wrong,
+        })
+    }
+}
+
+pub fn submit(args: SubmitArgs) -> Result<(), String> {
+    let year = years::year(args.year)?;
+    let day = years::find(year, args.day)?;
+    let dir = year.input_dir(args.day);
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let path = dir.join("input.txt");
+            day.solve(args.part, &run::read_input(&path)?)
+                .map_err(|e| format!("{}: {e}", path.display()))?
+        }
+    };
+
+    let mut log = Log::load(&dir).map_err(|e| e.to_string())?;
+    match log.check(args.part, &answer) {
+        Check::Seen(Verdict::Correct) => {
+            println!("{answer} was already accepted");
+            return Ok(());
+        }
+        Check::Seen(verdict) => {
+            return Err(format!(
+                "{answer} was already rejected ({verdict}), not submitting it again"
+            ));
+        }
+        Check::OutOfBounds(bounds) => {
+            eprintln!("warning: {answer} is outside the known bounds, the answer is {bounds}");
+        }
+        Check::Fresh => {}
+    }
+
+    let verdict = LocalJudge::load(&dir)?.judge(args.part, &answer)?;
+    log.record(Submission {
+        part: args.part,
+        answer: answer.clone(),
+        verdict,
+    })
+    .map_err(|e| e.to_string())?;
+    println!(
+        "{} day {} part {}: {answer} is {verdict}",
+        year.year, args.day, args.part
+    );
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use aoc_core::answers;
+use aoc_core::{Day, Part, Year};
+use rayon::prelude::*;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// How an answer compares with the answers registry.
+pub enum Check {
+    Correct,
+    Wrong(String),
+    Unknown,
+    Failed,
+}
+
+impl Check {
+    /// Compare a solver's answer with the recorded one. Gives the text to
+    /// show as the answer, which is the error when either side failed.
+    pub fn of(answer: Result<String, String>, expected: Result<Option<String>, String>) -> (String, Check) {
+        match (answer, expected) {
+            (Err(e), _) | (_, Err(e)) => (e, Check::Failed),
+            (Ok(answer), Ok(Some(expected))) if answer == expected => (answer, Check::Correct),
+            (Ok(answer), Ok(Some(expected))) => (answer, Check::Wrong(expected)),
+            (Ok(answer), Ok(None)) => (answer, Check::Unknown),
+        }
+    }
+
+    /// Whether the answer counts against the run.
+    pub fn failed(&self) -> bool {
+        matches!(self, Check::Wrong(_) | Check::Failed)
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Correct => write!(f, "ok"),
+            Check::Wrong(expected) => write!(f, "wrong, expected {expected}"),
+            Check::Unknown => write!(f, "no recorded answer"),
+            Check::Failed => write!(f, "error"),
+        }
+    }
+}
+
+struct Row {
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: String,
+    time: Duration,
+    check: Check,
+}
+
+/// Solve every part of every day of `years` on the thread pool, then print
+/// one table of answers checked against the registry.
+pub fn run_all(years: &[&'static Year], parts: &[Part], markdown: bool) -> Result<(), String> {
+    let jobs: Vec<(&Year, &Day, Part)> = years
+        .iter()
+        .flat_map(|year| year.days.iter().map(move |day| (*year, day)))
+        .flat_map(|(year, day)| parts.iter().map(move |&part| (year, day, part)))
+        .collect();
+    let rows: Vec<Row> = jobs
+        .into_par_iter()
+        .map(|(year, day, part)| solve(year, day, part))
+        .collect();
+
+    let header = ["year", "day", "part", "answer", "time", "check"];
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.year.to_string(),
+                row.day.to_string(),
+                row.part.to_string(),
+                row.answer.clone(),
+                format!("{:.2?}", row.time),
+                row.check.to_string(),
+            ]
+        })
+        .collect();
+    if markdown {
+        print_markdown(&header, &cells);
+    } else {
+        print_aligned(&header, &cells, &[true, true, true, true, true, false]);
+    }
+
+    let bad = rows.iter().filter(|row| row.check.failed()).count();
+    match bad {
+        0 => Ok(()),
+        _ => Err(format!("{bad} of {} answers failed or did not match", rows.len())),
+    }
+}
+
+fn solve(year: &Year, day: &Day, part: Part) -> Row {
+    let dir = year.input_dir(day.day);
+    let known = answers::load(&dir).map_err(|e| e.to_string());
+    let recorded = known
+        .as_ref()
+        .ok()
+        .and_then(|known| answers::find(known, "input.txt", part));
+    let params = recorded.map(|k| k.params.as_slice()).unwrap_or_default();
+
+    let start = Instant::now();
+    let answer = std::fs::read_to_string(dir.join("input.txt"))
+        .map_err(|e| format!("failed to read input.txt: {e}"))
+        .and_then(|input| {
+            day.solve_with(part, &input, params)
+                .map_err(|e| e.to_string())
+        });
+    let time = start.elapsed();
+
+    let expected = known
+        .as_ref()
+        .map(|_| recorded.map(|k| k.answer.clone()))
+        .map_err(Clone::clone);
+    let (answer, check) = Check::of(answer, expected);
+    Row {
+        year: year.year,
+        day: day.day,
+        part,
+        answer,
+        time,
+        check,
+    }
+}
+
+/// Left-aligned text columns, the `numeric` ones right-aligned.
+pub fn print_aligned<R: AsRef<[String]>>(header: &[&str], cells: &[R], numeric: &[bool]) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in cells {
+        for (width, cell) in widths.iter_mut().zip(row.as_ref()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let line = |row: Vec<&str>| {
+        let cells: Vec<String> = (0..row.len())
+            .map(|i| match numeric[i] {
+                true => format!("{:>w$}", row[i], w = widths[i]),
+                false => format!("{:<w$}", row[i], w = widths[i]),
+            })
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+    line(header.to_vec());
+    for row in cells {
+        line(row.as_ref().iter().map(String::as_str).collect());
+    }
+}
+
+fn print_markdown(header: &[&str; 6], cells: &[[String; 6]]) {
+    println!("| {} |", header.join(" | "));
+    println!("|{}", "---:|".repeat(5) + ":---|");
+    for row in cells {
+        println!("| {} |", row.join(" | "));
+    }
+}
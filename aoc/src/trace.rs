@@ -0,0 +1,109 @@
+use crate::run;
+use crate::years;
+use aoc_core::trace::Field;
+use aoc_core::{Override, Params, Part, Trace};
+use clap::{Args, ValueEnum};
+use serde_json::Value;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct TraceArgs {
+    /// Year of the day; the most recent year when omitted
+    #[arg(long)]
+    year: Option<u32>,
+    /// Day to trace
+    #[arg(long)]
+    day: u32,
+    /// Puzzle input, `-` for stdin; defaults to the day's `input.txt`
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Set one of the day's parameters, e.g. `--param start=0`; repeat for more
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<Override>,
+    #[arg(long, value_enum, default_value_t = Format::Csv)]
+    format: Format,
+    /// Where to write the trace, stdout when omitted
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// A header row, then one row per step
+    Csv,
+    /// One JSON object per step
+    Jsonl,
+}
+
+/// Write one record per step of a day's solution, to diff against another
+/// run's trace and find the first step where they part.
+pub fn trace(args: TraceArgs) -> Result<(), String> {
+    let year = years::year(args.year)?;
+    let day = years::find(year, args.day)?;
+    let tracer = year
+        .tracer(day.day)
+        .ok_or_else(|| format!("no tracer for {} day {}", year.year, day.day))?;
+    let path = args
+        .input
+        .unwrap_or_else(|| year.input_dir(day.day).join("input.txt"));
+    let input = run::read_input(&path)?;
+    // A trace follows the input through both parts, with part 1's value of
+    // any parameter that differs between them
+    let params = Params::new(day.params, Part::One, &args.params).map_err(|e| e.to_string())?;
+    let trace = tracer
+        .trace(&input, &params)
+        .map_err(|e| format!("{}: {e}", run::source_name(&path)))?;
+
+    let written = match &args.output {
+        Some(output) => {
+            let file = File::create(output).map_err(|e| format!("failed to write {}: {e}", output.display()))?;
+            write(&mut BufWriter::new(file), &trace, args.format)
+        }
+        None => write(&mut io::stdout().lock(), &trace, args.format),
+    };
+    written.map_err(|e| format!("failed to write the trace: {e}"))
+}
+
+fn write(out: &mut impl Write, trace: &Trace, format: Format) -> io::Result<()> {
+    match format {
+        Format::Csv => {
+            writeln!(out, "{}", trace.columns.join(","))?;
+            for step in &trace.steps {
+                let row: Vec<String> = step.iter().map(csv_field).collect();
+                writeln!(out, "{}", row.join(","))?;
+            }
+        }
+        Format::Jsonl => {
+            // Written by hand to keep the keys in column order
+            for step in &trace.steps {
+                let record: Vec<String> = trace
+                    .columns
+                    .iter()
+                    .zip(step)
+                    .map(|(column, field)| format!("{}:{}", Value::from(*column), json_field(field)))
+                    .collect();
+                writeln!(out, "{{{}}}", record.join(","))?;
+            }
+        }
+    }
+    out.flush()
+}
+
+/// A field quoted when it would otherwise break the row.
+fn csv_field(field: &Field) -> String {
+    let text = field.to_string();
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+fn json_field(field: &Field) -> Value {
+    match field {
+        Field::Int(n) => Value::from(*n),
+        Field::Text(text) => Value::from(text.as_str()),
+    }
+}
@@ -0,0 +1,176 @@
+use crate::summary;
+use crate::years;
+use aoc_core::Part;
+use clap::Args;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+/// How long to keep collecting events after the first one, so one save
+/// that touches a file several times only triggers one run.
+const SETTLE: Duration = Duration::from_millis(200);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Year of the day; the most recent year when omitted
+    #[arg(long)]
+    year: Option<u32>,
+    /// Day to watch
+    #[arg(long)]
+    day: u32,
+    /// Part to solve, both when omitted
+    #[arg(long)]
+    part: Option<Part>,
+    /// Puzzle input to watch; defaults to the day's `input.txt`
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+/// The answer of one run, or what kept it from finishing.
+#[derive(Clone)]
+struct Outcome {
+    answer: String,
+    time: Duration,
+}
+
+/// Re-run a day whenever its module or input changes, rebuilding first
+/// when the module changed, and show each answer and time next to the
+/// previous run's. Runs are separate processes, so the rebuilt code is
+/// the code that runs, and times include starting the process.
+pub fn watch(args: WatchArgs) -> Result<(), String> {
+    let year = years::year(args.year)?;
+    let day = years::find(year, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let module = canonical(&year.root().join("src").join(format!("day{}.rs", day.day)))?;
+    let input = canonical(&args.input.unwrap_or_else(|| year.input_dir(day.day).join("input.txt")))?;
+    let exe = env::current_exe().map_err(|e| format!("cannot find the aoc binary: {e}"))?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|e| format!("failed to watch files: {e}"))?;
+    // Watch the directories, as editors often save by replacing the file
+    for path in [&module, &input] {
+        let dir = path.parent().unwrap_or(path);
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("failed to watch {}: {e}", dir.display()))?;
+    }
+    eprintln!("watching {} and {}", module.display(), input.display());
+
+    let mut previous: Vec<Option<Outcome>> = vec![None; parts.len()];
+    let mut source_changed = true;
+    loop {
+        if source_changed && !build() {
+            eprintln!("build failed, waiting for the next change");
+        } else {
+            let latest: Vec<Outcome> = parts
+                .iter()
+                .map(|&part| solve(&exe, year.year, day.day, part, &input))
+                .collect();
+            print_comparison(&parts, &previous, &latest);
+            previous = latest.into_iter().map(Some).collect();
+        }
+        source_changed = wait(&rx, &module, &input)?;
+    }
+}
+
+fn canonical(path: &Path) -> Result<PathBuf, String> {
+    path.canonicalize()
+        .map_err(|e| format!("cannot watch {}: {e}", path.display()))
+}
+
+/// Block until the module or the input changes. True when the module did.
+fn wait(rx: &Receiver<notify::Result<notify::Event>>, module: &Path, input: &Path) -> Result<bool, String> {
+    let mut module_changed = false;
+    let mut input_changed = false;
+    let mut deadline: Option<Instant> = None;
+    loop {
+        let event = match deadline {
+            None => rx.recv().map_err(|_| "stopped watching files".to_string())?,
+            Some(deadline) => match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(event) => event,
+                Err(_) => return Ok(module_changed),
+            },
+        };
+        let event = event.map_err(|e| format!("failed to watch files: {e}"))?;
+        if matches!(event.kind, EventKind::Access(_)) {
+            continue;
+        }
+        module_changed |= event.paths.iter().any(|p| p == module);
+        input_changed |= event.paths.iter().any(|p| p == input);
+        if (module_changed || input_changed) && deadline.is_none() {
+            deadline = Some(Instant::now() + SETTLE);
+        }
+    }
+}
+
+/// Rebuild the runner with the same profile as this one, showing compiler
+/// errors as they come.
+fn build() -> bool {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.current_dir(workspace).args(["build", "--quiet", "--package", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.status().is_ok_and(|status| status.success())
+}
+
+/// Solve one part in a fresh `aoc run`.
+fn solve(exe: &Path, year: u32, day: u32, part: Part, input: &Path) -> Outcome {
+    let mut command = Command::new(exe);
+    command.args(["run", "--year", &year.to_string(), "--day", &day.to_string()]);
+    command.args(["--part", &part.to_string()]).arg("--input").arg(input);
+    if aoc_core::answer::is_checked() {
+        command.arg("--checked");
+    }
+    let start = Instant::now();
+    let output = command.output();
+    let time = start.elapsed();
+    let answer = match output {
+        Err(e) => format!("error: failed to start {}: {e}", exe.display()),
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            // `aoc run` prints `2025 day 9 part 2: <answer>`
+            let line = stdout.lines().last().unwrap_or_default();
+            line.split_once(": ").map_or(line, |(_, answer)| answer).to_string()
+        }
+        Ok(output) => String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    };
+    Outcome { answer, time }
+}
+
+fn print_comparison(parts: &[Part], previous: &[Option<Outcome>], latest: &[Outcome]) {
+    let header = ["part", "previous", "time", "answer", "time", ""];
+    let cells: Vec<[String; 6]> = parts
+        .iter()
+        .zip(previous.iter().zip(latest))
+        .map(|(part, (before, now))| {
+            let (answer, time) = match before {
+                Some(before) => (before.answer.clone(), format!("{:.2?}", before.time)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            let changed = match before {
+                Some(before) if before.answer != now.answer => "changed",
+                _ => "",
+            };
+            [
+                part.to_string(),
+                answer,
+                time,
+                now.answer.clone(),
+                format!("{:.2?}", now.time),
+                changed.to_string(),
+            ]
+        })
+        .collect();
+    println!();
+    summary::print_aligned(&header, &cells, &[true, true, true, true, true, false]);
+}
@@ -0,0 +1,52 @@
+use aoc_core::{Day, Year};
+use std::str::FromStr;
+
+/// Every year with solutions, oldest first. A new year's crate only has to
+/// be added here and as a dependency.
+pub const YEARS: &[Year] = &[aoc2025::YEAR];
+
+/// The years a command applies to, from `--year 2025` or `--year all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Years {
+    All,
+    One(u32),
+}
+
+impl FromStr for Years {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Years::All),
+            _ => s
+                .parse()
+                .map(Years::One)
+                .map_err(|_| format!("invalid year `{s}`, expected a year or `all`")),
+        }
+    }
+}
+
+/// The solutions of `year`, the most recent year when it is `None`.
+pub fn year(year: Option<u32>) -> Result<&'static Year, String> {
+    match year {
+        Some(year) => YEARS
+            .iter()
+            .find(|y| y.year == year)
+            .ok_or_else(|| format!("no solutions for year {year}")),
+        None => YEARS.last().ok_or_else(|| "no solutions for any year".to_string()),
+    }
+}
+
+/// Every year picked by `years`, the most recent one when it is `None`.
+pub fn select(years: Option<Years>) -> Result<Vec<&'static Year>, String> {
+    match years {
+        Some(Years::All) => Ok(YEARS.iter().collect()),
+        Some(Years::One(y)) => Ok(vec![year(Some(y))?]),
+        None => Ok(vec![year(None)?]),
+    }
+}
+
+pub fn find(year: &Year, day: u32) -> Result<&'static Day, String> {
+    year.day(day)
+        .ok_or_else(|| format!("no solution for {} day {day}", year.year))
+}
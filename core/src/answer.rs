@@ -0,0 +1,186 @@
+//! Integer answers that cannot silently overflow. Arithmetic runs on `i64`
+//! until a result does not fit; then it either moves on to a big integer,
+//! or, in checked mode, fails with the step that overflowed.
+
+use crate::AocError;
+use num_bigint::BigInt;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Checked mode is on in debug builds, and with `--checked` in any build.
+static CHECKED: AtomicBool = AtomicBool::new(cfg!(debug_assertions));
+
+/// Make overflow an error instead of a promotion to a big integer.
+pub fn set_checked(checked: bool) {
+    CHECKED.store(checked, Ordering::Relaxed);
+}
+
+pub fn is_checked() -> bool {
+    CHECKED.load(Ordering::Relaxed)
+}
+
+/// What to do when a result no longer fits in an `i64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Overflow {
+    Promote,
+    Fail,
+}
+
+/// An integer answer, kept as an `i64` while it fits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Small(i64),
+    Big(BigInt),
+}
+
+impl Answer {
+    pub const ZERO: Answer = Answer::Small(0);
+    pub const ONE: Answer = Answer::Small(1);
+
+    /// `self + rhs`, where `step` names the sum in an overflow error.
+    pub fn add(self, rhs: impl Into<Answer>, step: &str) -> Result<Answer, AocError> {
+        self.combine(rhs.into(), '+', i64::checked_add, step, mode())
+    }
+
+    /// `self - rhs`, where `step` names the difference in an overflow error.
+    pub fn sub(self, rhs: impl Into<Answer>, step: &str) -> Result<Answer, AocError> {
+        self.combine(rhs.into(), '-', i64::checked_sub, step, mode())
+    }
+
+    /// `self * rhs`, where `step` names the product in an overflow error.
+    pub fn mul(self, rhs: impl Into<Answer>, step: &str) -> Result<Answer, AocError> {
+        self.combine(rhs.into(), '*', i64::checked_mul, step, mode())
+    }
+
+    fn combine(
+        self,
+        rhs: Answer,
+        op: char,
+        checked: fn(i64, i64) -> Option<i64>,
+        step: &str,
+        overflow: Overflow,
+    ) -> Result<Answer, AocError> {
+        if let (Answer::Small(a), Answer::Small(b)) = (&self, &rhs)
+            && let Some(result) = checked(*a, *b)
+        {
+            return Ok(Answer::Small(result));
+        }
+        if overflow == Overflow::Fail {
+            let message = format!("overflow in {step}: {self} {op} {rhs} does not fit in 64 bits");
+            return Err(AocError::new(message));
+        }
+        let (a, b) = (self.into_big(), rhs.into_big());
+        Ok(Answer::from(match op {
+            '+' => a + b,
+            '-' => a - b,
+            _ => a * b,
+        }))
+    }
+
+    fn into_big(self) -> BigInt {
+        match self {
+            Answer::Small(n) => BigInt::from(n),
+            Answer::Big(n) => n,
+        }
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Answer::ZERO
+    }
+}
+
+fn mode() -> Overflow {
+    if is_checked() { Overflow::Fail } else { Overflow::Promote }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Small(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Big(BigInt::from(n)), Answer::Small)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::from(n as u64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Small(n.into())
+    }
+}
+
+/// Big integers that fit are kept small, so equal values compare equal.
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        i64::try_from(&n).map_or(Answer::Big(n), Answer::Small)
+    }
+}
+
+/// Ordered by value, whichever way each side is stored.
+impl Ord for Answer {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Answer::Small(a), Answer::Small(b)) => a.cmp(b),
+            _ => self.clone().into_big().cmp(&other.clone().into_big()),
+        }
+    }
+}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Small(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow_promotes_or_fails() {
+        let big = Answer::from(u64::MAX);
+        let product = big
+            .clone()
+            .combine(Answer::from(3u32), '*', i64::checked_mul, "product", Overflow::Promote);
+        assert_eq!(product.unwrap().to_string(), "55340232221128654845");
+
+        let error = Answer::from(i64::MAX)
+            .combine(Answer::ONE, '+', i64::checked_add, "total", Overflow::Fail)
+            .unwrap_err();
+        assert!(error.to_string().contains("overflow in total"), "{error}");
+
+        let back = big.combine(Answer::Small(i64::MIN), '+', i64::checked_add, "sum", Overflow::Promote);
+        assert_eq!(back.unwrap(), Answer::Small(i64::MAX));
+
+        let below = Answer::from(i64::MIN)
+            .combine(Answer::ONE, '-', i64::checked_sub, "difference", Overflow::Promote);
+        assert_eq!(below.unwrap().to_string(), "-9223372036854775809");
+    }
+
+    #[test]
+    fn big_and_small_answers_compare_by_value() {
+        let big = Answer::from(u64::MAX);
+        assert!(Answer::from(i64::MAX) < big);
+        assert!(Answer::Big(BigInt::from(-1) << 64) < Answer::from(i64::MIN));
+        assert!(Answer::ZERO < Answer::ONE);
+    }
+}
@@ -0,0 +1,113 @@
+//! Known answers, one `answers.txt` per day next to its inputs:
+//!
+//! ```text
+//! # input part answer
+//! input.txt 1 1040
+//! input_test.txt 2 6
+//! ```
+//!
+//! An answer for an input that needs other parameters than the puzzle's
+//! lists them after it, e.g. `input_test.txt 1 40 connect=10`.
+//!
+//! Inputs kept elsewhere, such as other accounts' inputs, can each have a
+//! sidecar file instead: `alice.answers` next to `alice.txt`, with the same
+//! lines minus the input column.
+
+use crate::{Override, Part};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const FILE_NAME: &str = "answers.txt";
+pub const SIDECAR_EXTENSION: &str = "answers";
+
+/// The accepted answer for one part of one input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Known {
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+    /// Parameters to solve `input` with, on top of the day's defaults
+    pub params: Vec<Override>,
+}
+
+pub fn parse(text: &str) -> Result<Vec<Known>, String> {
+    parse_lines(text, None)
+}
+
+/// Parse the sidecar answers of the input file `input`.
+pub fn parse_sidecar(text: &str, input: &str) -> Result<Vec<Known>, String> {
+    parse_lines(text, Some(input))
+}
+
+/// Parse answers lines, which name their input unless it is given.
+fn parse_lines(text: &str, sidecar_of: Option<&str>) -> Result<Vec<Known>, String> {
+    let mut known = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields: Vec<&str> = line.split_whitespace().collect();
+        if let Some(input) = sidecar_of {
+            fields.insert(0, input);
+        }
+        let [input, part, answer, ref params @ ..] = fields[..] else {
+            let input = if sidecar_of.is_some() { "" } else { "input " };
+            return Err(format!(
+                "line {}: expected `{input}part answer [name=value ...]`, got `{line}`",
+                number + 1
+            ));
+        };
+        let part = part
+            .parse()
+            .map_err(|e| format!("line {}: {e}", number + 1))?;
+        let params = params
+            .iter()
+            .map(|param| param.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("line {}: {e}", number + 1))?;
+        known.push(Known {
+            input: input.to_string(),
+            part,
+            answer: answer.to_string(),
+            params,
+        });
+    }
+    Ok(known)
+}
+
+/// Load the answers recorded in `dir`, empty if it has no answers file.
+pub fn load(dir: &Path) -> io::Result<Vec<Known>> {
+    read(&dir.join(FILE_NAME), parse)
+}
+
+/// Where the sidecar answers of the input file `input` live.
+pub fn sidecar(input: &Path) -> PathBuf {
+    input.with_extension(SIDECAR_EXTENSION)
+}
+
+/// Load the sidecar answers of the input file `input`, empty if it has none.
+pub fn load_sidecar(input: &Path) -> io::Result<Vec<Known>> {
+    let name = input.file_name().unwrap_or_default().to_string_lossy();
+    read(&sidecar(input), |text| parse_sidecar(text, &name))
+}
+
+fn read(path: &Path, parse: impl Fn(&str) -> Result<Vec<Known>, String>) -> io::Result<Vec<Known>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {e}", path.display())))
+}
+
+/// Entry recorded for `part` of the input file `input`.
+pub fn find<'a>(known: &'a [Known], input: &str, part: Part) -> Option<&'a Known> {
+    known.iter().find(|k| k.input == input && k.part == part)
+}
+
+/// Answer recorded for `part` of the input file `input`.
+pub fn lookup<'a>(known: &'a [Known], input: &str, part: Part) -> Option<&'a str> {
+    find(known, input, part).map(|k| k.answer.as_str())
+}
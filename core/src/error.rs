@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Where in the puzzle input a problem was found. Lines and columns
+/// start at 1, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+/// A puzzle input the solutions cannot make sense of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    /// Filled in by [`crate::Day`] when the error leaves the solution.
+    pub day: Option<u32>,
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl AocError {
+    /// An error not tied to a particular spot in the input.
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            day: None,
+            location: None,
+            message: message.into(),
+        }
+    }
+
+    /// An error about `text`, found at `line` and `column`.
+    pub fn at(line: usize, column: usize, text: impl Into<String>, message: impl Into<String>) -> Self {
+        AocError {
+            day: None,
+            location: Some(Location {
+                line,
+                column,
+                text: text.into(),
+            }),
+            message: message.into(),
+        }
+    }
+
+    /// An error about `token`, a slice of the text of line `line`.
+    /// The column is worked out from where `token` sits in `text`.
+    pub fn in_line(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        Self::at(line, column_of(text, token), token, message)
+    }
+
+    pub fn in_day(mut self, day: u32) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}: ")?;
+        }
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "line {}, column {}: {} (found `{}`)",
+                location.line, location.column, self.message, location.text
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+/// Column (1-based, in characters) at which `token` starts within `text`,
+/// or 1 if `token` is not a slice of `text`.
+pub fn column_of(text: &str, token: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    if offset <= text.len() && text.is_char_boundary(offset) {
+        text[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+/// Parse `token`, a slice of line `line` whose text is `text`, as a number.
+pub fn number<T: FromStr>(line: usize, text: &str, token: &str) -> Result<T, AocError> {
+    token
+        .parse()
+        .map_err(|_| AocError::in_line(line, text, token, "invalid number"))
+}
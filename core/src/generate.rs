@@ -0,0 +1,44 @@
+//! Seeded generators of valid puzzle inputs, for fuzzing the solutions and
+//! for seeing how they scale past the one real input of each day.
+
+use rand::SeedableRng;
+use std::fmt;
+
+/// The random source handed to generators. ChaCha keeps a seed producing the
+/// same input on every platform and rand release.
+pub type InputRng = rand_chacha::ChaCha8Rng;
+
+/// A generator of inputs for one day.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub day: u32,
+    /// What `size` counts for this day, e.g. "junction boxes"
+    pub unit: &'static str,
+    generate: fn(&mut InputRng, usize) -> String,
+}
+
+impl Generator {
+    pub const fn new(day: u32, unit: &'static str, generate: fn(&mut InputRng, usize) -> String) -> Self {
+        Generator {
+            day,
+            unit,
+            generate,
+        }
+    }
+
+    /// A puzzle input of about `size` units. The same seed and size always
+    /// give the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let mut rng = InputRng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+impl fmt::Debug for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator")
+            .field("day", &self.day)
+            .field("unit", &self.unit)
+            .finish()
+    }
+}
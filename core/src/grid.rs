@@ -0,0 +1,202 @@
+//! A rectangular grid of cells, for the puzzles drawn as character maps.
+
+use crate::AocError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell position, counted from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+}
+
+/// Offsets of the four orthogonal neighbours, as `(row, col)`.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of all eight neighbours, diagonals included.
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows, which must all be as wide as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(AocError::new("the grid is empty"));
+        }
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                let message = format!("row {} is {} wide, the first row is {width}", i + 1, row.len());
+                return Err(AocError::new(message));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    /// The position `rows` down and `cols` right of `pos`, if it is on the grid.
+    pub fn offset(&self, pos: Pos, rows: isize, cols: isize) -> Option<Pos> {
+        let row = pos.row.checked_add_signed(rows)?;
+        let col = pos.col.checked_add_signed(cols)?;
+        let pos = Pos::new(row, col);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Up, left, right and down neighbours of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&(rows, cols)| self.offset(pos, rows, cols))
+    }
+
+    /// All eight neighbours of `pos` that are on the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND
+            .iter()
+            .filter_map(move |&(rows, cols)| self.offset(pos, rows, cols))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Position of the first cell equal to `value`, reading row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        let index = self.cells.iter().position(|cell| cell == value)?;
+        Some(Pos::new(index / self.width, index % self.width))
+    }
+
+    /// The grid flipped over its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {height}x{width} grid"))
+    }
+}
+
+/// Renders the grid back to text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = parse::grid("abc\ndef", Some, "a letter").unwrap();
+        let corner: String = grid.neighbours8(Pos::new(0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, "bde");
+        let middle: String = grid.neighbours4(Pos::new(1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(middle, "bdf");
+        assert_eq!(grid.offset(Pos::new(1, 2), 0, 1), None);
+    }
+
+    #[test]
+    fn transpose_and_render() {
+        let grid = parse::grid("S..\n.^.\r\n", Some, "a cell").unwrap();
+        assert_eq!(grid.find(&'^'), Some(Pos::new(1, 1)));
+        assert_eq!(grid.transpose().to_string(), "S.\n.^\n..\n");
+        assert_eq!(grid.to_string(), "S..\n.^.\n");
+    }
+}
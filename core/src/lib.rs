@@ -0,0 +1,23 @@
+//! Pieces shared by every year's solutions: the `Solution` trait and the
+//! registries built on it, input parsing, and the answer bookkeeping files.
+
+pub mod answer;
+pub mod answers;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod params;
+pub mod parse;
+pub mod solution;
+pub mod submissions;
+pub mod trace;
+pub mod variants;
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use generate::{Generator, InputRng};
+pub use grid::{Grid, Pos};
+pub use params::{Override, Param, Params};
+pub use solution::{Day, Part, Solution, Timing, Year};
+pub use trace::{Trace, Tracer};
+pub use variants::Variant;
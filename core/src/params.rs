@@ -0,0 +1,161 @@
+//! Named constants a solution depends on, such as the size of the dial or
+//! how many pairs of boxes to connect. Each day declares its parameters with
+//! the puzzle's values as defaults. Example inputs and puzzle variants
+//! override them with `--param name=value` or in the answers registry.
+
+use crate::{AocError, Part};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A parameter a day declares, with the value the real puzzle uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    /// The default in part 2, when it is not the same as in part 1
+    pub part2_default: Option<&'static str>,
+    /// What the parameter controls, for listings and error messages
+    pub about: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: &'static str, about: &'static str) -> Self {
+        Param {
+            name,
+            default,
+            part2_default: None,
+            about,
+        }
+    }
+
+    /// A parameter whose puzzle value changes between the parts, like how
+    /// many batteries to turn on.
+    pub const fn per_part(
+        name: &'static str,
+        part1: &'static str,
+        part2: &'static str,
+        about: &'static str,
+    ) -> Self {
+        Param {
+            part2_default: Some(part2),
+            ..Param::new(name, part1, about)
+        }
+    }
+}
+
+/// A `name=value` replacing the default of one parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Override {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.is_empty() => Ok(Override {
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+            _ => Err(format!("expected a parameter like `name=value`, got `{s}`")),
+        }
+    }
+}
+
+impl Display for Override {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+/// The values of a day's parameters for one run of one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, String)>,
+}
+
+impl Params {
+    /// The defaults of every parameter in `declared` for `part`, which
+    /// picks part 2's own default where a parameter has one.
+    pub fn defaults(declared: &[Param], part: Part) -> Self {
+        let default = |p: &Param| match (part, p.part2_default) {
+            (Part::Two, Some(part2)) => part2,
+            _ => p.default,
+        };
+        Params {
+            values: declared.iter().map(|p| (p.name, default(p).to_string())).collect(),
+        }
+    }
+
+    /// The defaults of `declared` for `part` with `overrides` applied in
+    /// order, so a later override of the same name wins.
+    pub fn new(declared: &[Param], part: Part, overrides: &[Override]) -> Result<Self, AocError> {
+        let mut params = Self::defaults(declared, part);
+        for o in overrides {
+            let Some((_, value)) = params.values.iter_mut().find(|(name, _)| *name == o.name)
+            else {
+                let known: Vec<&str> = declared.iter().map(|p| p.name).collect();
+                let known = match known.is_empty() {
+                    true => "it has none".to_string(),
+                    false => format!("expected one of {}", known.join(", ")),
+                };
+                return Err(AocError::new(format!(
+                    "unknown parameter `{}`, {known}",
+                    o.name
+                )));
+            };
+            *value = o.value.clone();
+        }
+        Ok(params)
+    }
+
+    /// The value of parameter `name`, parsed as a `T`.
+    pub fn get<T>(&self, name: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some((_, value)) = self.values.iter().find(|(n, _)| *n == name) else {
+            return Err(AocError::new(format!("parameter `{name}` is not declared")));
+        };
+        value
+            .parse()
+            .map_err(|e| AocError::new(format!("parameter `{name}={value}`: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[Param::new("connect", "1000", "pairs to connect")];
+
+    #[test]
+    fn overrides_replace_defaults() {
+        assert_eq!(Params::defaults(DECLARED, Part::One).get::<usize>("connect"), Ok(1000));
+
+        let overrides = ["connect=5".parse().unwrap(), "connect=10".parse().unwrap()];
+        let params = Params::new(DECLARED, Part::One, &overrides).unwrap();
+        assert_eq!(params.get::<usize>("connect"), Ok(10));
+
+        let error = Params::new(DECLARED, Part::One, &["size=3".parse().unwrap()]).unwrap_err();
+        assert!(error.message.contains("expected one of connect"), "{error}");
+        let error = Params::new(DECLARED, Part::One, &["connect=x".parse().unwrap()])
+            .unwrap()
+            .get::<usize>("connect")
+            .unwrap_err();
+        assert!(error.message.contains("connect=x"), "{error}");
+    }
+
+    #[test]
+    fn part2_defaults_give_way_to_overrides() {
+        let declared = &[Param::per_part("k", "2", "12", "batteries")];
+        assert_eq!(Params::defaults(declared, Part::One).get::<usize>("k"), Ok(2));
+        assert_eq!(Params::defaults(declared, Part::Two).get::<usize>("k"), Ok(12));
+
+        let params = Params::new(declared, Part::Two, &["k=3".parse().unwrap()]).unwrap();
+        assert_eq!(params.get::<usize>("k"), Ok(3));
+    }
+}
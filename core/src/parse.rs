@@ -0,0 +1,225 @@
+//! Building blocks for the input shapes the puzzles use. Everything here
+//! tolerates CRLF line endings, trailing whitespace and trailing blank
+//! lines, and reports errors against the line and column they come from.
+
+use crate::AocError;
+use crate::error::{self, column_of};
+use crate::grid::Grid;
+use std::str::FromStr;
+
+/// One line of the input, without its line ending or trailing whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number in the whole input
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// A bracketed group on a line, like `(1,3)` in a machine description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group<'a> {
+    /// The opening bracket: one of `[`, `(` or `{`
+    pub open: char,
+    /// Text between the brackets
+    pub body: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error about `token`, which must be a slice of this line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> AocError {
+        AocError::in_line(self.number, self.text, token, message)
+    }
+
+    /// An error about the whole line.
+    pub fn error_line(&self, message: impl Into<String>) -> AocError {
+        AocError::at(self.number, 1, self.text, message)
+    }
+
+    /// Parse `token`, a slice of this line, as a number.
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, AocError> {
+        error::number(self.number, self.text, token)
+    }
+
+    /// Split `token` around the first `separator`, trimming both halves.
+    /// `expected` describes the shape in the error, e.g. "a range like `3-5`".
+    pub fn split_once(
+        &self,
+        token: &'a str,
+        separator: char,
+        expected: &str,
+    ) -> Result<(&'a str, &'a str), AocError> {
+        token
+            .split_once(separator)
+            .map(|(left, right)| (left.trim(), right.trim()))
+            .ok_or_else(|| self.error(token, format!("expected {expected}")))
+    }
+
+    /// Parse a `separator` delimited list of numbers, like `162,817,812`.
+    pub fn list<T: FromStr>(&self, token: &str, separator: char) -> Result<Vec<T>, AocError> {
+        token
+            .split(separator)
+            .map(|item| self.number(item.trim()))
+            .collect()
+    }
+
+    /// Every character of the line as a decimal digit.
+    pub fn digits(&self) -> Result<Vec<u32>, AocError> {
+        self.text
+            .chars()
+            .enumerate()
+            .map(|(col, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| AocError::at(self.number, col + 1, c, "expected a digit"))
+            })
+            .collect()
+    }
+
+    /// Split a `name: a b c` line into the name and its neighbours.
+    pub fn adjacency(&self) -> Result<(&'a str, Vec<&'a str>), AocError> {
+        match self.text.split_once(':') {
+            Some((name, rest)) if !name.trim().is_empty() && !rest.contains(':') => {
+                Ok((name.trim(), rest.split_whitespace().collect()))
+            }
+            _ => Err(self.error_line("expected a line like `name: a b c`")),
+        }
+    }
+
+    /// Split a line of bracketed groups, like `[.##.] (3) (1,3) {3,5}`.
+    /// Only whitespace may appear between groups.
+    pub fn groups(&self) -> Result<Vec<Group<'a>>, AocError> {
+        let mut groups = Vec::new();
+        let mut rest = self.text;
+        while let Some(open) = rest.trim_start().chars().next() {
+            rest = rest.trim_start();
+            let close = match open {
+                '[' => ']',
+                '(' => ')',
+                '{' => '}',
+                _ => {
+                    let token = &rest[..open.len_utf8()];
+                    return Err(self.error(token, "expected `[`, `(` or `{`"));
+                }
+            };
+            let Some(end) = rest.find(close) else {
+                return Err(self.error(rest, format!("missing closing `{close}`")));
+            };
+            groups.push(Group {
+                open,
+                body: &rest[1..end],
+            });
+            rest = &rest[end + 1..];
+        }
+        Ok(groups)
+    }
+
+    /// Column (1-based) of `token` within this line.
+    pub fn column(&self, token: &str) -> usize {
+        column_of(self.text, token)
+    }
+}
+
+/// The lines of `input`, numbered from 1, with CRLF endings, trailing
+/// whitespace and trailing blank lines removed.
+pub fn lines(input: &str) -> Vec<Line<'_>> {
+    let mut lines: Vec<Line> = input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line {
+            number: i + 1,
+            text: text.trim_end(),
+        })
+        .collect();
+    while lines.last().is_some_and(|line| line.text.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Blocks of lines separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = Vec::new();
+    let mut current = Vec::new();
+    for line in lines(input) {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                sections.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// A rectangular grid of cells, one character each. `cell` maps a character
+/// to its value or rejects it; `expected` describes the valid characters.
+pub fn grid<T>(
+    input: &str,
+    cell: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<Grid<T>, AocError> {
+    let lines = lines(input);
+    let Some(width) = lines.first().map(|line| line.text.chars().count()) else {
+        return Err(AocError::new("the grid is empty"));
+    };
+
+    let mut rows = Vec::with_capacity(lines.len());
+    for line in lines {
+        let row = line
+            .text
+            .chars()
+            .enumerate()
+            .map(|(col, c)| {
+                cell(c).ok_or_else(|| {
+                    AocError::at(line.number, col + 1, c, format!("expected {expected}"))
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+        if row.len() != width {
+            let message = format!("row is {} wide, the first row is {width}", row.len());
+            return Err(line.error_line(message));
+        }
+        rows.push(row);
+    }
+    Grid::from_rows(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crlf_and_trailing_blank_lines_are_ignored() {
+        let sections = sections("3-5 \r\n10-14\r\n\r\n1\r\n5\r\n\r\n");
+        let texts: Vec<Vec<&str>> = sections
+            .iter()
+            .map(|s| s.iter().map(|line| line.text).collect())
+            .collect();
+        assert_eq!(texts, [vec!["3-5", "10-14"], vec!["1", "5"]]);
+        assert_eq!(sections[1][0].number, 4);
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        let line = lines("aaa\n162,8x7,812")[1];
+        let error = line.list::<u64>(line.text, ',').unwrap_err();
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+        assert_eq!(location.text, "8x7");
+    }
+
+    #[test]
+    fn groups_split_machine_lines() {
+        let line = lines("[.##.] (3) (1,3) {3,5,4,7}")[0];
+        let groups = line.groups().unwrap();
+        let opens: String = groups.iter().map(|g| g.open).collect();
+        assert_eq!(opens, "[(({");
+        assert_eq!(groups[3].body, "3,5,4,7");
+
+        let error = lines("[.#] (1 {2}")[0].groups().unwrap_err();
+        assert_eq!(error.location.unwrap().column, 6);
+    }
+}
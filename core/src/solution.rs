@@ -0,0 +1,225 @@
+use crate::AocError;
+use crate::generate::Generator;
+use crate::params::{Override, Param, Params};
+use crate::trace::Tracer;
+use crate::variants::Variant;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, info_span};
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("unknown part `{s}`, expected 1 or 2")),
+        }
+    }
+}
+
+/// Common shape of every day: parse the puzzle input once,
+/// then solve both parts from the parsed value.
+pub trait Solution {
+    /// Day of the month the puzzle was published.
+    const DAY: u32;
+
+    /// Constants the parts read from their `Params` instead of hard-coding.
+    const PARAMS: &'static [Param] = &[];
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input, params: &Params) -> Result<Self::Part1, AocError>;
+    fn part2(input: &Self::Input, params: &Params) -> Result<Self::Part2, AocError>;
+}
+
+/// Type-erased entry for a day, so days can be picked by number
+/// at runtime instead of by name at compile time.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    pub params: &'static [Param],
+    solve: fn(Part, &str, &Params) -> Result<String, AocError>,
+    time: fn(&str) -> Result<Timing, AocError>,
+}
+
+/// How long each phase of a single run took.
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            params: S::PARAMS,
+            solve: solve::<S>,
+            time: time::<S>,
+        }
+    }
+
+    /// Parse `input` and solve the requested part, formatted for display.
+    pub fn solve(&self, part: Part, input: &str) -> Result<String, AocError> {
+        (self.solve)(part, input, &Params::defaults(self.params, part))
+    }
+
+    /// Like [`Day::solve`], with some parameters set to other values than
+    /// the puzzle's.
+    pub fn solve_with(
+        &self,
+        part: Part,
+        input: &str,
+        overrides: &[Override],
+    ) -> Result<String, AocError> {
+        let params = Params::new(self.params, part, overrides).map_err(|e| e.in_day(self.day))?;
+        (self.solve)(part, input, &params)
+    }
+
+    /// Run parse, part 1 and part 2 once, timing each phase on its own.
+    pub fn time(&self, input: &str) -> Result<Timing, AocError> {
+        (self.time)(input)
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("day", &self.day).finish()
+    }
+}
+
+fn solve<S: Solution>(part: Part, input: &str, params: &Params) -> Result<String, AocError> {
+    let _span = info_span!("solve", day = S::DAY, %part).entered();
+    let start = Instant::now();
+    let answer = S::parse(input).and_then(|parsed| {
+        debug!(elapsed = ?start.elapsed(), "parsed input");
+        match part {
+            Part::One => S::part1(&parsed, params).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&parsed, params).map(|answer| answer.to_string()),
+        }
+    });
+    match &answer {
+        Ok(answer) => info!(%answer, elapsed = ?start.elapsed(), "solved"),
+        Err(e) => debug!(error = %e, "failed"),
+    }
+    answer.map_err(|e| e.in_day(S::DAY))
+}
+
+fn time<S: Solution>(input: &str) -> Result<Timing, AocError> {
+    let [part1_params, part2_params] = Part::ALL.map(|part| Params::defaults(S::PARAMS, part));
+    let timed = || {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed, &part1_params)?);
+        let part1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed, &part2_params)?);
+        let part2 = start.elapsed();
+
+        Ok(Timing {
+            parse,
+            part1,
+            part2,
+        })
+    };
+    timed().map_err(|e: AocError| e.in_day(S::DAY))
+}
+
+/// Every solved day of one year, and where that year's inputs live.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    pub year: u32,
+    root: &'static str,
+    pub days: &'static [Day],
+    pub variants: &'static [Variant],
+    pub generators: &'static [Generator],
+    pub tracers: &'static [Tracer],
+}
+
+impl Year {
+    /// `root` is the year crate's `CARGO_MANIFEST_DIR`, which holds its
+    /// `src/` and `DayN` directories.
+    pub const fn new(year: u32, root: &'static str, days: &'static [Day]) -> Self {
+        Year {
+            year,
+            root,
+            days,
+            variants: &[],
+            generators: &[],
+            tracers: &[],
+        }
+    }
+
+    /// Register alternative implementations to check the days against.
+    pub const fn with_variants(self, variants: &'static [Variant]) -> Self {
+        Year { variants, ..self }
+    }
+
+    /// Register input generators for the days.
+    pub const fn with_generators(self, generators: &'static [Generator]) -> Self {
+        Year { generators, ..self }
+    }
+
+    /// Register step-by-step tracers for the days.
+    pub const fn with_tracers(self, tracers: &'static [Tracer]) -> Self {
+        Year { tracers, ..self }
+    }
+
+    /// The input generator of a day, if it has one.
+    pub fn generator(&self, day: u32) -> Option<&'static Generator> {
+        self.generators.iter().find(|g| g.day == day)
+    }
+
+    /// The tracer of a day, if it has one.
+    pub fn tracer(&self, day: u32) -> Option<&'static Tracer> {
+        self.tracers.iter().find(|t| t.day == day)
+    }
+
+    /// Look up a day by its number.
+    pub fn day(&self, n: u32) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == n)
+    }
+
+    /// Root of the year's crate.
+    pub fn root(&self) -> &'static Path {
+        Path::new(self.root)
+    }
+
+    /// Directory holding the inputs and answers of a day, e.g. `2025/Day9`.
+    pub fn input_dir(&self, day: u32) -> PathBuf {
+        self.root().join(format!("Day{day}"))
+    }
+}
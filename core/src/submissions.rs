@@ -0,0 +1,225 @@
+//! Log of every answer tried for a puzzle, one `submissions.txt` per day:
+//!
+//! ```text
+//! # part answer verdict
+//! 2 4174379265 wrong
+//! 2 46666175279 correct
+//! ```
+
+use crate::Part;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const FILE_NAME: &str = "submissions.txt";
+
+/// What the puzzle server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!("unknown verdict `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Range a new answer must fall in, from earlier too-low/too-high verdicts.
+/// Both ends are exclusive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+    pub above: Option<i128>,
+    pub below: Option<i128>,
+}
+
+impl Bounds {
+    pub fn contains(&self, value: i128) -> bool {
+        self.above.is_none_or(|low| value > low) && self.below.is_none_or(|high| value < high)
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.above, self.below) {
+            (Some(low), Some(high)) => write!(f, "between {low} and {high}"),
+            (Some(low), None) => write!(f, "above {low}"),
+            (None, Some(high)) => write!(f, "below {high}"),
+            (None, None) => write!(f, "unbounded"),
+        }
+    }
+}
+
+/// What the log already knows about a candidate answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Never tried and consistent with every earlier verdict.
+    Fresh,
+    /// Already submitted, with this verdict.
+    Seen(Verdict),
+    /// Never tried, but earlier verdicts already rule it out.
+    OutOfBounds(Bounds),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Log {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl Log {
+    /// Load the log kept in `dir`, empty if nothing was submitted yet.
+    pub fn load(dir: &Path) -> io::Result<Log> {
+        let path = dir.join(FILE_NAME);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let entries = parse(&text).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {e}", path.display()))
+        })?;
+        Ok(Log { path, entries })
+    }
+
+    pub fn entries(&self) -> &[Submission] {
+        &self.entries
+    }
+
+    pub fn bounds(&self, part: Part) -> Bounds {
+        let mut bounds = Bounds::default();
+        for entry in self.entries.iter().filter(|e| e.part == part) {
+            let Ok(value) = entry.answer.parse::<i128>() else {
+                continue;
+            };
+            match entry.verdict {
+                Verdict::TooLow => bounds.above = bounds.above.max(Some(value)),
+                Verdict::TooHigh => {
+                    bounds.below = Some(bounds.below.map_or(value, |high| high.min(value)))
+                }
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Check {
+        if let Some(seen) = self
+            .entries
+            .iter()
+            .find(|e| e.part == part && e.answer == answer)
+        {
+            return Check::Seen(seen.verdict);
+        }
+        let bounds = self.bounds(part);
+        match answer.parse::<i128>() {
+            Ok(value) if !bounds.contains(value) => Check::OutOfBounds(bounds),
+            _ => Check::Fresh,
+        }
+    }
+
+    /// Append a verdict to the log, on disk and in memory.
+    pub fn record(&mut self, submission: Submission) -> io::Result<()> {
+        let new_file = !self.path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if new_file {
+            writeln!(file, "# part answer verdict")?;
+        }
+        writeln!(
+            file,
+            "{} {} {}",
+            submission.part, submission.answer, submission.verdict
+        )?;
+        self.entries.push(submission);
+        Ok(())
+    }
+}
+
+pub fn parse(text: &str) -> Result<Vec<Submission>, String> {
+    let mut entries = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [part, answer, verdict] = fields[..] else {
+            return Err(format!(
+                "line {}: expected `part answer verdict`, got `{line}`",
+                number + 1
+            ));
+        };
+        entries.push(Submission {
+            part: part.parse().map_err(|e| format!("line {}: {e}", number + 1))?,
+            answer: answer.to_string(),
+            verdict: verdict
+                .parse()
+                .map_err(|e| format!("line {}: {e}", number + 1))?,
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(text: &str) -> Log {
+        Log {
+            path: PathBuf::new(),
+            entries: parse(text).unwrap(),
+        }
+    }
+
+    #[test]
+    fn rejected_answers_are_remembered() {
+        let log = log("2 4174379265 wrong\n");
+        assert_eq!(log.check(Part::Two, "4174379265"), Check::Seen(Verdict::Wrong));
+        assert_eq!(log.check(Part::One, "4174379265"), Check::Fresh);
+    }
+
+    #[test]
+    fn bounds_come_from_the_tightest_verdicts() {
+        let log = log("1 10 too-low\n1 50 too-high\n1 20 too-low\n1 40 too-high\n");
+        let bounds = log.bounds(Part::One);
+        assert_eq!(bounds, Bounds { above: Some(20), below: Some(40) });
+        assert_eq!(log.check(Part::One, "30"), Check::Fresh);
+        assert_eq!(log.check(Part::One, "45"), Check::OutOfBounds(bounds));
+        assert_eq!(log.check(Part::One, "20"), Check::Seen(Verdict::TooLow));
+    }
+}
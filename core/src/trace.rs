@@ -0,0 +1,77 @@
+//! Step-by-step traces of a solution, one record per instruction of the
+//! input, for finding where a wrong answer first goes astray by diffing
+//! against someone else's trace.
+
+use crate::{AocError, Params};
+use std::fmt;
+
+/// One value of a traced step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Field::Int(n) => write!(f, "{n}"),
+            Field::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl From<i64> for Field {
+    fn from(n: i64) -> Self {
+        Field::Int(n)
+    }
+}
+
+impl From<i32> for Field {
+    fn from(n: i32) -> Self {
+        Field::Int(n.into())
+    }
+}
+
+impl From<usize> for Field {
+    fn from(n: usize) -> Self {
+        Field::Int(n as i64)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(text: &str) -> Self {
+        Field::Text(text.to_string())
+    }
+}
+
+/// Every step a solution took, each with one field per column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub columns: &'static [&'static str],
+    pub steps: Vec<Vec<Field>>,
+}
+
+/// A day's tracer, working from the raw puzzle input.
+#[derive(Clone, Copy)]
+pub struct Tracer {
+    pub day: u32,
+    trace: fn(&str, &Params) -> Result<Trace, AocError>,
+}
+
+impl Tracer {
+    pub const fn new(day: u32, trace: fn(&str, &Params) -> Result<Trace, AocError>) -> Self {
+        Tracer { day, trace }
+    }
+
+    /// Trace the day's solution over `input`.
+    pub fn trace(&self, input: &str, params: &Params) -> Result<Trace, AocError> {
+        (self.trace)(input, params).map_err(|e| e.in_day(self.day))
+    }
+}
+
+impl fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer").field("day", &self.day).finish()
+    }
+}
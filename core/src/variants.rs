@@ -0,0 +1,69 @@
+//! Alternative implementations of a part, kept next to the main solution so
+//! they can be checked against it.
+
+use crate::{AocError, Day, Params, Part};
+use std::fmt;
+
+/// A named alternative way to solve one part of a day.
+#[derive(Clone, Copy)]
+pub struct Variant {
+    pub day: u32,
+    pub part: Part,
+    pub name: &'static str,
+    solve: fn(&str, &Params) -> Result<String, AocError>,
+}
+
+impl Variant {
+    pub const fn new(
+        day: u32,
+        part: Part,
+        name: &'static str,
+        solve: fn(&str, &Params) -> Result<String, AocError>,
+    ) -> Self {
+        Variant {
+            day,
+            part,
+            name,
+            solve,
+        }
+    }
+
+    /// Solve the variant's part from the raw puzzle input, with `params`
+    /// built for that part.
+    pub fn solve(&self, input: &str, params: &Params) -> Result<String, AocError> {
+        (self.solve)(input, params).map_err(|e| e.in_day(self.day))
+    }
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Variant")
+            .field("day", &self.day)
+            .field("part", &self.part)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// Solve `part` of `day` on `input` with the main solution and with every
+/// one of `variants` for that part. On disagreement, describes what each of
+/// them answered. Everything runs with the day's default parameters.
+pub fn compare(day: &Day, part: Part, variants: &[Variant], input: &str) -> Result<(), String> {
+    let params = Params::defaults(day.params, part);
+    let mut answers = vec![("main", day.solve(part, input))];
+    for variant in variants.iter().filter(|v| v.day == day.day && v.part == part) {
+        answers.push((variant.name, variant.solve(input, &params)));
+    }
+
+    if answers.iter().all(|(_, answer)| *answer == answers[0].1) {
+        return Ok(());
+    }
+    let described: Vec<String> = answers
+        .iter()
+        .map(|(name, answer)| match answer {
+            Ok(answer) => format!("{name} gave {answer}"),
+            Err(e) => format!("{name} failed: {e}"),
+        })
+        .collect();
+    Err(format!("day {} part {part}: {}", day.day, described.join(", ")))
+}
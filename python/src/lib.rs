@@ -0,0 +1,270 @@
+//! Python bindings for the 2025 solutions, so notebooks can drive the Rust
+//! code and plot what it computes:
+//!
+//! ```python
+//! import aoc2025
+//! from aoc2025 import day8
+//!
+//! text = aoc2025.input(8)
+//! day8.part1(text, connect=10)
+//! day8.circuit_sizes(text, connect=10)
+//! ```
+//!
+//! Each day is a submodule with `parse`, which hands back the parsed input
+//! as plain Python data, `part1` and `part2`, which take the day's
+//! parameters as keyword arguments, and `params`, which lists them.
+//! Errors in the input raise `ValueError`.
+
+use aoc2025::YEAR;
+use aoc2025::day1::Zeros;
+use aoc_core::{AocError, Override, Param, Params, Part, Solution};
+use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::fs;
+
+fn error(e: AocError) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// Keyword arguments as parameter overrides; values go through `str()`.
+fn overrides(params: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<Override>> {
+    let Some(params) = params else {
+        return Ok(Vec::new());
+    };
+    params
+        .iter()
+        .map(|(name, value)| {
+            Ok(Override {
+                name: name.extract()?,
+                value: value.str()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn part(part: u32) -> PyResult<Part> {
+    part.to_string().parse().map_err(PyValueError::new_err)
+}
+
+/// Solve `part` of `day` on `input`, formatted like the CLI prints it.
+#[pyfunction]
+#[pyo3(signature = (day, part, input, **params))]
+fn solve(day: u32, part: u32, input: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
+    let part = self::part(part)?;
+    let Some(solution) = YEAR.day(day) else {
+        return Err(PyValueError::new_err(format!("no solution for {} day {day}", YEAR.year)));
+    };
+    solution.solve_with(part, input, &overrides(params)?).map_err(error)
+}
+
+/// Every solved day.
+#[pyfunction]
+fn days() -> Vec<u32> {
+    YEAR.days.iter().map(|day| day.day).collect()
+}
+
+/// Contents of one of a day's input files, `input.txt` by default.
+#[pyfunction]
+#[pyo3(signature = (day, name = "input.txt"))]
+fn input(day: u32, name: &str) -> PyResult<String> {
+    let path = YEAR.input_dir(day).join(name);
+    fs::read_to_string(&path)
+        .map_err(|e| PyValueError::new_err(format!("failed to read {}: {e}", path.display())))
+}
+
+/// Defaults that change between the parts read like `2 in part 1, 12 in part 2`.
+fn describe(params: &[Param]) -> Vec<(&'static str, String, &'static str)> {
+    let default = |p: &Param| match p.part2_default {
+        Some(part2) => format!("{} in part 1, {part2} in part 2", p.default),
+        None => p.default.to_string(),
+    };
+    params.iter().map(|p| (p.name, default(p), p.about)).collect()
+}
+
+/// Parse the day's input and its parameters for `part`, for helpers that
+/// expose the data between parsing the input and answering that part.
+fn parsed<S: Solution>(
+    input: &str,
+    part: Part,
+    params: Option<&Bound<'_, PyDict>>,
+) -> PyResult<(S::Input, Params)> {
+    let parsed = S::parse(input).map_err(error)?;
+    let params = Params::new(S::PARAMS, part, &overrides(params)?).map_err(error)?;
+    Ok((parsed, params))
+}
+
+/// Make `module` importable as `aoc2025.<name>` as well as an attribute.
+fn add_submodule(parent: &Bound<'_, PyModule>, module: &Bound<'_, PyModule>) -> PyResult<()> {
+    parent.add_submodule(module)?;
+    let name = format!("aoc2025.{}", module.name()?);
+    parent.py().import("sys")?.getattr("modules")?.set_item(name, module)
+}
+
+/// A submodule for one day, with `parse` converting its parsed input with
+/// `$plain` into something Python understands.
+macro_rules! day {
+    ($module:ident, $solution:ty, |$parsed:ident| $plain:expr) => {
+        mod $module {
+            use super::*;
+
+            /// Parse the puzzle input into plain Python data.
+            #[pyfunction]
+            fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyAny>> {
+                let $parsed = <$solution>::parse(input).map_err(error)?;
+                $plain.into_bound_py_any(py)
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (input, **params))]
+            fn part1(input: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
+                solve(<$solution>::DAY, 1, input, params)
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (input, **params))]
+            fn part2(input: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
+                solve(<$solution>::DAY, 2, input, params)
+            }
+
+            /// The day's parameters, as `(name, default, about)`.
+            #[pyfunction]
+            fn params() -> Vec<(&'static str, String, &'static str)> {
+                describe(<$solution>::PARAMS)
+            }
+
+            pub fn module<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyModule>> {
+                let module = PyModule::new(py, stringify!($module))?;
+                module.add_function(wrap_pyfunction!(parse, &module)?)?;
+                module.add_function(wrap_pyfunction!(part1, &module)?)?;
+                module.add_function(wrap_pyfunction!(part2, &module)?)?;
+                module.add_function(wrap_pyfunction!(params, &module)?)?;
+                Ok(module)
+            }
+        }
+    };
+}
+
+// Rotations as `(dial, clicks)`, the dial `None` unless named, clicks
+// positive to the right.
+day!(day1, aoc2025::day1::Day1, |rotations| {
+    let rotations = rotations.into_iter().map(|r| (r.dial, r.clicks));
+    rotations.collect::<Vec<_>>()
+});
+// ID ranges as `(first, last)`.
+day!(day2, aoc2025::day2::Day2, |ranges| ranges);
+// Battery banks as lists of digits.
+day!(day3, aoc2025::day3::Day3, |banks| banks);
+// The floor, one string per row.
+day!(day4, aoc2025::day4::Day4, |grid| grid.rows().map(String::from_iter).collect::<Vec<_>>());
+// Fresh ranges as `(start, end)`, and the ingredient IDs.
+day!(day5, aoc2025::day5::Day5, |inventory| {
+    let fresh: Vec<(i64, i64)> = inventory.fresh.iter().map(|f| (f.start, f.end)).collect();
+    (fresh, inventory.ingredients)
+});
+// The worksheet lines, untrimmed so the columns line up.
+day!(day6, aoc2025::day6::Day6, |lines| lines);
+// The manifold, one string per row.
+day!(day7, aoc2025::day7::Day7, |grid| grid.rows().map(String::from_iter).collect::<Vec<_>>());
+// Junction boxes as `(x, y, z)`.
+day!(day8, aoc2025::day8::Day8, |positions| positions.iter().map(|p| (p.x, p.y, p.z)).collect::<Vec<_>>());
+// Red tiles as `(x, y)`.
+day!(day9, aoc2025::day9::Day9, |tiles| tiles.iter().map(|c| (c.x, c.y)).collect::<Vec<_>>());
+// Machines as `(lights, buttons, joltage)`.
+day!(day10, aoc2025::day10::Day10, |machines| {
+    let machines = machines.into_iter().map(|m| (m.lights, m.buttons, m.joltage));
+    machines.collect::<Vec<_>>()
+});
+// Each device with the devices its outputs feed.
+day!(day11, aoc2025::day11::Day11, |graph| {
+    let outputs = graph.nodes.into_values().map(|node| (node.name, node.connections));
+    outputs.collect::<std::collections::HashMap<_, _>>()
+});
+
+/// Where the dial points after each rotation.
+#[pyfunction(name = "positions")]
+#[pyo3(signature = (input, **params))]
+fn day1_positions(input: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<i32>> {
+    let (rotations, params) = parsed::<aoc2025::day1::Day1>(input, Part::One, params)?;
+    aoc2025::day1::positions(&rotations, &params).map_err(error)
+}
+
+/// Zeros of each dial of the lock as `(dial, landings, crossings)`.
+#[pyfunction(name = "dials")]
+#[pyo3(signature = (input, **params))]
+fn day1_dials(input: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<(Option<String>, i64, i64)>> {
+    let (rotations, params) = parsed::<aoc2025::day1::Day1>(input, Part::One, params)?;
+    let zeros = aoc2025::day1::dial_zeros(&rotations, &params).map_err(error)?;
+    Ok(zeros.into_iter().map(|(dial, z)| (dial, z.landings, z.crossings)).collect())
+}
+
+/// Zeros from every start of a dial, `None` for the unnamed one: a dict of
+/// `landings` and `crossings`, each a list indexed by the start, and `best`
+/// and `worst`, each a dict of the start giving the most or fewest of them.
+#[pyfunction(name = "starts")]
+#[pyo3(signature = (input, dial = None, **params))]
+fn day1_starts<'py>(
+    py: Python<'py>,
+    input: &str,
+    dial: Option<&str>,
+    params: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let (rotations, params) = parsed::<aoc2025::day1::Day1>(input, Part::One, params)?;
+    let starts = aoc2025::day1::starts(&rotations, dial, &params).map_err(error)?;
+    let landings = |z: &Zeros| z.landings;
+    let crossings = |z: &Zeros| z.crossings;
+    let best = PyDict::new(py);
+    best.set_item("landings", starts.best(landings))?;
+    best.set_item("crossings", starts.best(crossings))?;
+    let worst = PyDict::new(py);
+    worst.set_item("landings", starts.worst(landings))?;
+    worst.set_item("crossings", starts.worst(crossings))?;
+    let found = PyDict::new(py);
+    found.set_item("landings", starts.zeros.iter().map(landings).collect::<Vec<_>>())?;
+    found.set_item("crossings", starts.zeros.iter().map(crossings).collect::<Vec<_>>())?;
+    found.set_item("best", best)?;
+    found.set_item("worst", worst)?;
+    Ok(found)
+}
+
+/// Sizes of the circuits after joining the closest pairs, largest first.
+#[pyfunction(name = "circuit_sizes")]
+#[pyo3(signature = (input, **params))]
+fn day8_circuit_sizes(input: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<usize>> {
+    let (positions, params) = parsed::<aoc2025::day8::Day8>(input, Part::One, params)?;
+    Ok(aoc2025::day8::circuit_sizes(&positions, params.get("connect").map_err(error)?))
+}
+
+#[pymodule]
+#[pyo3(name = "aoc2025")]
+fn aoc_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_function(wrap_pyfunction!(input, m)?)?;
+
+    let day1 = day1::module(py)?;
+    day1.add_function(wrap_pyfunction!(day1_positions, &day1)?)?;
+    day1.add_function(wrap_pyfunction!(day1_dials, &day1)?)?;
+    day1.add_function(wrap_pyfunction!(day1_starts, &day1)?)?;
+    let day8 = day8::module(py)?;
+    day8.add_function(wrap_pyfunction!(day8_circuit_sizes, &day8)?)?;
+    let modules = [
+        day1,
+        day2::module(py)?,
+        day3::module(py)?,
+        day4::module(py)?,
+        day5::module(py)?,
+        day6::module(py)?,
+        day7::module(py)?,
+        day8,
+        day9::module(py)?,
+        day10::module(py)?,
+        day11::module(py)?,
+    ];
+    for module in &modules {
+        add_submodule(m, module)?;
+    }
+    Ok(())
+}
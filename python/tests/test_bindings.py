@@ -0,0 +1,61 @@
+"""Smoke tests of the `aoc2025` module: every exported function is called
+once on the example inputs. Run with `maturin develop && pytest` from the
+`python` directory."""
+
+import importlib
+
+import pytest
+
+import aoc2025
+from aoc2025 import day1, day8
+
+
+def example(day):
+    return aoc2025.input(day, "input_test.txt")
+
+
+def test_days_are_importable_modules():
+    assert aoc2025.days() == list(range(1, 12))
+    for day in aoc2025.days():
+        module = importlib.import_module(f"aoc2025.day{day}")
+        for name in ("parse", "part1", "part2", "params"):
+            assert callable(getattr(module, name)), f"day{day}.{name}"
+
+
+def test_solve_matches_the_recorded_answers():
+    assert aoc2025.solve(1, 1, example(1)) == "3"
+    assert day1.part2(example(1)) == "6"
+    assert day8.part1(example(8), connect=10) == "40"
+
+
+def test_params_are_listed_and_set_by_keyword():
+    names = [name for name, _, _ in day1.params()]
+    assert names == ["size", "start", "dials"]
+    defaults = {name: default for name, default, _ in aoc2025.day3.params()}
+    assert defaults == {"k": "2 in part 1, 12 in part 2"}
+    assert day1.part2(example(1), size=60, start=0) != day1.part2(example(1))
+
+
+def test_day1_helpers():
+    text = example(1)
+    rotations = day1.parse(text)
+    assert rotations[0] == (None, -68)
+    assert day1.positions(text)[0] == 82
+    assert day1.dials(text) == [(None, 3, 6)]
+    starts = day1.starts(text)
+    assert (starts["landings"][50], starts["crossings"][50]) == (3, 6)
+    assert set(starts["best"]) == {"landings", "crossings"}
+
+
+def test_day8_circuit_sizes():
+    sizes = day8.circuit_sizes(example(8), connect=10)
+    assert sizes[0] * sizes[1] * sizes[2] == 40
+
+
+def test_errors_raise_value_error():
+    with pytest.raises(ValueError, match="expected direction"):
+        day1.part1("X5\n")
+    with pytest.raises(ValueError, match="unknown parameter"):
+        day8.part1(example(8), joins=3)
+    with pytest.raises(ValueError):
+        aoc2025.solve(12, 1, "")